@@ -83,6 +83,16 @@ fn bench_ifft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
     });
 }
 
+fn bench_fft_in_blocks<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let (domain, mut a) = create_evaluation_domain::<F>(*degree);
+    // Split the domain into blocks of about the square root of its size.
+    let block = 1 << (domain.size().trailing_zeros() / 2);
+
+    b.iter(|| {
+        domain.fft_in_blocks(&mut a, block);
+    });
+}
+
 fn bench_coset_fft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
     let (domain, mut a) = create_evaluation_domain::<F>(*degree);
 
@@ -102,6 +112,8 @@ fn bench_coset_ifft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
 fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     let description = format!("{name:?} - subgroup_fft_in_place");
     setup_bench(c, &description, bench_fft_in_place::<F>);
+    let description = format!("{name:?} - subgroup_fft_in_blocks");
+    setup_bench(c, &description, bench_fft_in_blocks::<F>);
     let description = format!("{name:?} - subgroup_ifft_in_place");
     setup_bench(c, &description, bench_ifft_in_place::<F>);
     let description = format!("{name:?} - coset_fft_in_place");
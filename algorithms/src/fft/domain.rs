@@ -23,21 +23,37 @@
 //! roots to be the powers of a 2^n root of unity in the field.
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
+//!
+//! The FFTs here are deterministic: their outputs are bit-identical regardless of the number of
+//! threads in the calling thread pool, of [`FftConfig`], and of the `serial` feature. Parallelism only
+//! changes which thread computes each element, and field arithmetic is exact, so every output element
+//! has the same value, and therefore the same representation, as when computed sequentially.
+//! Callers can rely on this for reproducible transcripts and for caching results by content hash.
 
 use crate::{
     cfg_chunks_mut,
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{DensePolynomial, DomainCoeff, Evaluations, SparsePolynomial},
 };
-use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
+use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field, PrimeField};
 #[cfg(not(feature = "serial"))]
 use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 
 use rand::Rng;
-use std::{borrow::Cow, fmt};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -73,6 +89,85 @@ pub fn log2(x: usize) -> u32 {
 #[cfg(not(feature = "serial"))]
 const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
+/// The largest domain whose elements are cached by [`EvaluationDomain::cached_elements`].
+/// This bounds the memory of each cached domain to `2^20` field elements.
+pub const MAX_CACHED_DOMAIN_SIZE: usize = 1 << 20;
+
+thread_local! {
+    /// The elements of the domains cached on this thread, keyed by the field type and domain size.
+    static ELEMENTS_CACHE: RefCell<HashMap<(TypeId, u64), Arc<dyn Any + Send + Sync>>> = RefCell::new(HashMap::new());
+}
+
+/// Clears the domain elements cached on this thread by [`EvaluationDomain::cached_elements`].
+pub fn clear_roots_cache() {
+    ELEMENTS_CACHE.with(|cache| cache.borrow_mut().clear());
+}
+
+/// The maximum depth of nested parallelism in FFTs, as set by [`FftConfig::install`].
+static MAX_PARALLEL_DEPTH: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Configures how deeply FFTs nest parallel tasks.
+///
+/// At depth `0`, FFTs run sequentially. At depth `1`, the butterflies of each round are split into
+/// parallel tasks, but each task runs sequentially, and so on. Bounding the depth avoids spawning
+/// many small tasks when FFTs are themselves run inside a busy or small thread pool.
+/// The default is unbounded, which parallelizes as much as the FFTs find beneficial.
+/// The configuration only affects performance, never the results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FftConfig {
+    max_parallel_depth: usize,
+}
+
+impl Default for FftConfig {
+    fn default() -> Self {
+        Self { max_parallel_depth: usize::MAX }
+    }
+}
+
+impl FftConfig {
+    /// Returns `self` with the maximum depth of nested parallelism set to `depth`.
+    pub fn with_max_parallel_depth(self, depth: usize) -> Self {
+        Self { max_parallel_depth: depth }
+    }
+
+    /// Returns the maximum depth of nested parallelism.
+    pub fn max_parallel_depth(&self) -> usize {
+        self.max_parallel_depth
+    }
+
+    /// Returns the configuration currently used by FFTs.
+    pub fn current() -> Self {
+        Self { max_parallel_depth: MAX_PARALLEL_DEPTH.load(Ordering::Relaxed) }
+    }
+
+    /// Use `self` for all subsequent FFTs, on all threads, returning the previous configuration.
+    pub fn install(self) -> Self {
+        Self { max_parallel_depth: MAX_PARALLEL_DEPTH.swap(self.max_parallel_depth, Ordering::Relaxed) }
+    }
+}
+
+/// An error that occurs when constructing an `EvaluationDomain`.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum DomainError {
+    /// The next power of two of the requested number of coefficients does not fit in a `usize`.
+    #[error("A domain for {num_coeffs} coefficients would have a size that overflows usize")]
+    SizeOverflow { num_coeffs: usize },
+    /// The requested domain is larger than the largest power-of-two subgroup of the field.
+    #[error(
+        "A domain for {num_coeffs} coefficients needs size 2^{log_size}, but the field supports at most 2^{two_adicity}"
+    )]
+    SizeExceedsTwoAdicity { num_coeffs: usize, log_size: u32, two_adicity: u32 },
+    /// The requested domain is larger than the largest power-of-two subgroup of the named field.
+    #[error(
+        "A domain of size {size} needs size 2^{log_size}, but the field {field} has two-adicity {two_adicity}, \
+         so its largest domain has size 2^{two_adicity}"
+    )]
+    UnsupportedByField { field: &'static str, size: usize, log_size: u32, two_adicity: u32 },
+    /// Two sets of evaluations that must share a domain are over domains of different sizes.
+    #[error("Expected evaluations over a domain of size {expected}, but found a domain of size {found}")]
+    DomainMismatch { expected: usize, found: usize },
+}
+
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
 /// a power-of-2.
@@ -112,34 +207,62 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Construct a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
+    /// Returns `None` if no such domain exists; see [`Self::try_new`] for the reason.
     pub fn new(num_coeffs: usize) -> Option<Self> {
+        Self::try_new(num_coeffs).ok()
+    }
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, or return a `DomainError` describing why
+    /// no such domain exists over `F`.
+    pub fn try_new(num_coeffs: usize) -> Result<Self, DomainError> {
         // Compute the size of our evaluation domain
-        let size = num_coeffs.checked_next_power_of_two()? as u64;
+        let size = num_coeffs.checked_next_power_of_two().ok_or(DomainError::SizeOverflow { num_coeffs })? as u64;
         let log_size_of_group = size.trailing_zeros();
 
         // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
-        if log_size_of_group > F::FftParameters::TWO_ADICITY {
-            return None;
+        let two_adicity = F::FftParameters::TWO_ADICITY;
+        let too_large = DomainError::SizeExceedsTwoAdicity { num_coeffs, log_size: log_size_of_group, two_adicity };
+        if log_size_of_group > two_adicity {
+            return Err(too_large);
         }
 
         // Compute the generator for the multiplicative subgroup.
         // It should be the 2^(log_size_of_group) root of unity.
-        let group_gen = F::get_root_of_unity(size as usize)?;
+        let group_gen = F::get_root_of_unity(size as usize).ok_or(too_large)?;
 
         // Check that it is indeed the 2^(log_size_of_group) root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
+        // The inverses below exist, as `size` is a power of two smaller than the characteristic,
+        // and the generators are nonzero.
         let size_as_field_element = F::from(size);
-        let size_inv = size_as_field_element.inverse()?;
+        let size_inv = size_as_field_element.inverse().expect("The domain size must be invertible");
 
-        Some(EvaluationDomain {
+        Ok(EvaluationDomain {
             size,
             log_size_of_group,
             size_as_field_element,
             size_inv,
             group_gen,
-            group_gen_inv: group_gen.inverse()?,
-            generator_inv: F::multiplicative_generator().inverse()?,
+            group_gen_inv: group_gen.inverse().expect("The group generator must be invertible"),
+            generator_inv: F::multiplicative_generator()
+                .inverse()
+                .expect("The multiplicative generator must be invertible"),
+        })
+    }
+
+    /// Construct a domain of size at least `size`, as in [`Self::try_new`].
+    ///
+    /// If `size` is too large for `F`, the error names the field along with its two-adicity, which
+    /// bounds the size of its domains. This helps diagnose circuits that fit in a domain over one field
+    /// but not over another, e.g. when moving them to a different curve.
+    pub fn new_for_size_and_field(size: usize) -> Result<Self, DomainError> {
+        Self::try_new(size).map_err(|error| match error {
+            DomainError::SizeExceedsTwoAdicity { log_size, two_adicity, .. } => {
+                DomainError::UnsupportedByField { field: core::any::type_name::<F>(), size, log_size, two_adicity }
+            }
+            error => error,
         })
     }
 
@@ -150,6 +273,19 @@ impl<F: FftField> EvaluationDomain<F> {
         if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY { Some(size) } else { None }
     }
 
+    /// Return the size of the smallest domain that is large enough for evaluations of a polynomial
+    /// of degree `degree`, i.e. the next power of two greater than `degree`.
+    /// Returns `None` if this exceeds the two-adicity of the field.
+    pub fn size_for_degree(degree: usize) -> Option<usize> {
+        Self::compute_size_of_domain(degree.checked_add(1)?)
+    }
+
+    /// Return the maximum degree of a polynomial that fits in a domain over `F`,
+    /// as determined by the two-adicity of the field.
+    pub fn max_supported_degree() -> usize {
+        1usize.checked_shl(F::FftParameters::TWO_ADICITY).map_or(usize::MAX, |max_size| max_size - 1)
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -170,6 +306,108 @@ impl<F: FftField> EvaluationDomain<F> {
         });
     }
 
+    /// Compute an FFT in place, in the same order as [`Self::fft_in_place`], by the "four-step" method:
+    /// writing `size = rows * block`, it performs `rows` transforms of size `block`, scales the results
+    /// by twiddle factors, and then performs `block` transforms of size `rows`. Each of the smaller transforms
+    /// works on a contiguous window of memory, at the cost of gathering the coefficients into a buffer of the
+    /// size of the domain. Whether this is faster than [`Self::fft_in_place`] depends on the machine; the
+    /// `subgroup_fft_in_blocks` benchmark compares the two. A `block` of `1` or at least the size of the domain
+    /// performs a single transform.
+    ///
+    /// Panics if `coeffs.len()` is not the size of the domain, or if `block` is not a power of two.
+    pub fn fft_in_blocks<T: DomainCoeff<F>>(&self, coeffs: &mut [T], block: usize) {
+        assert!(block.is_power_of_two(), "The block size must be a power of two");
+        // Precompute the larger sub-domain, from which the smaller one is derived.
+        let size = if block == 1 || block >= self.size() { self.size() } else { block.max(self.size() / block) };
+        let pc = Self::new(size).unwrap().precompute_fft();
+        self.fft_in_blocks_with_pc(coeffs, block, &pc)
+    }
+
+    /// Compute an FFT in place as in [`Self::fft_in_blocks`], taking the precomputation of the smaller
+    /// transforms from `pc`, which can be the precomputation of `self` or of any domain that contains
+    /// both sub-domains. This allows the precomputation to be reused across calls.
+    ///
+    /// Panics if `coeffs.len()` is not the size of the domain, if `block` is not a power of two,
+    /// or if `pc` is for a domain that is smaller than either sub-domain.
+    pub fn fft_in_blocks_with_pc<T: DomainCoeff<F>>(&self, coeffs: &mut [T], block: usize, pc: &FFTPrecomputation<F>) {
+        assert_eq!(coeffs.len(), self.size(), "The number of coefficients must match the domain size");
+        assert!(block.is_power_of_two(), "The block size must be a power of two");
+        if block == 1 || block >= self.size() {
+            let pc = pc.precomputation_for_subdomain(self).expect("The precomputation is too small for the domain");
+            return execute_with_max_available_threads(|| self.in_order_fft_in_place_with_pc(coeffs, &pc));
+        }
+        let rows = self.size() / block;
+        let inner = Self::new(block).unwrap();
+        let outer = Self::new(rows).unwrap();
+        let inner_pc = pc.precomputation_for_subdomain(&inner).expect("The precomputation is too small for the domain");
+        let outer_pc = pc.precomputation_for_subdomain(&outer).expect("The precomputation is too small for the domain");
+
+        execute_with_max_available_threads(|| {
+            // Gather the coefficients `coeffs[i + rows * j]` for each `i` into the `i`-th row, and transform each row.
+            // The rows are gathered in tiles, so that each read from `coeffs` is a contiguous run of `tile` elements.
+            let tile = core::cmp::min(FFT_IN_BLOCKS_TILE_SIZE, rows);
+            let mut transformed_rows = vec![T::zero(); self.size()];
+            let coeffs_ref = &*coeffs;
+            cfg_chunks_mut!(transformed_rows, tile * block).enumerate().for_each(|(t, tile_rows)| {
+                for (j, run) in coeffs_ref.chunks_exact(rows).enumerate() {
+                    for (k, c) in run[t * tile..(t + 1) * tile].iter().enumerate() {
+                        tile_rows[k * block + j] = *c;
+                    }
+                }
+                for (k, row) in tile_rows.chunks_exact_mut(block).enumerate() {
+                    inner.in_order_fft_in_place_with_pc(row, &inner_pc);
+                    // Scale the `j`-th element of the `i`-th row by `g^{i * j}`.
+                    Self::distribute_powers(row, self.group_gen.pow([(t * tile + k) as u64]));
+                }
+            });
+
+            // Gather the `j`-th elements of the rows into the `j`-th column, and transform each column.
+            // The `k`-th element of the `j`-th column is the evaluation at `g^{block * k + j}`, so the columns
+            // are scattered back into `coeffs` in tiles, as they were gathered.
+            let tile = core::cmp::min(FFT_IN_BLOCKS_TILE_SIZE, block);
+            let mut columns = vec![T::zero(); tile * rows];
+            for t in 0..block / tile {
+                for (k, run) in transformed_rows.chunks_exact(block).enumerate() {
+                    for (j, c) in run[t * tile..(t + 1) * tile].iter().enumerate() {
+                        columns[j * rows + k] = *c;
+                    }
+                }
+                cfg_chunks_mut!(columns, rows)
+                    .for_each(|column| outer.in_order_fft_in_place_with_pc(column, &outer_pc));
+                for (k, run) in coeffs.chunks_exact_mut(block).enumerate() {
+                    for (j, c) in run[t * tile..(t + 1) * tile].iter_mut().enumerate() {
+                        *c = columns[j * rows + k];
+                    }
+                }
+            }
+        });
+    }
+
+    /// Compute an FFT of a sparse polynomial, by summing the contribution `c * (g^e)^i` of each
+    /// term `c * x^e` to the `i`-th evaluation. This takes `O(num_terms * self.size)` time,
+    /// which is cheaper than a dense FFT when `p` has few terms.
+    pub fn fft_sparse(&self, p: &SparsePolynomial<F>) -> Vec<F> {
+        let size = self.size();
+        let terms = p.coeffs().map(|(e, c)| (*c, self.group_gen.pow([*e as u64]))).collect::<Vec<_>>();
+
+        #[cfg(not(feature = "serial"))]
+        let chunk_size = core::cmp::max(size / max_available_threads(), MIN_PARALLEL_CHUNK_SIZE);
+        #[cfg(feature = "serial")]
+        let chunk_size = size;
+
+        let mut evals = vec![F::zero(); size];
+        cfg_chunks_mut!(evals, chunk_size).enumerate().for_each(|(i, chunk)| {
+            for (c, g) in &terms {
+                let mut cur = *c * g.pow([(i * chunk_size) as u64]);
+                for eval in chunk.iter_mut() {
+                    *eval += cur;
+                    cur *= g;
+                }
+            }
+        });
+        evals
+    }
+
     /// Compute an IFFT.
     pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
         let mut evals = evals.to_vec();
@@ -218,7 +456,8 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
-    fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
+    /// The powers of `g` are computed in parallel chunks, each starting from its own power of `g`.
+    pub fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
     }
 
@@ -297,14 +536,155 @@ impl<F: FftField> EvaluationDomain<F> {
     /// This evaluates the vanishing polynomial for this domain at tau.
     /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size - 1`.
     pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
-        tau.pow([self.size]) - F::one()
+        self.pow_size(tau) - F::one()
+    }
+
+    /// This evaluates the vanishing polynomial for the coset `offset * self` at tau.
+    /// This polynomial is `z(X) = X^self.size - offset^self.size`.
+    pub fn evaluate_coset_vanishing_polynomial(&self, offset: F, tau: F) -> F {
+        self.pow_size(tau) - self.pow_size(offset)
+    }
+
+    /// Returns whether `point` is an element of the domain, i.e. whether `point^self.size == 1`.
+    /// This costs `log(self.size)` squarings, rather than a scan over `self.elements()`.
+    pub fn contains(&self, point: F) -> bool {
+        self.evaluate_vanishing_polynomial(point).is_zero()
+    }
+
+    /// Compute `x^self.size` by repeated squaring, since `self.size` is a power of two.
+    fn pow_size(&self, x: F) -> F {
+        let mut result = x;
+        for _ in 0..self.log_size_of_group {
+            result.square_in_place();
+        }
+        result
     }
 
     /// Return an iterator over the elements of the domain.
+    /// The elements are computed as they are iterated over, so the iterator does not allocate.
+    /// See [`Self::cached_elements`] to reuse the elements across calls.
     pub fn elements(&self) -> Elements<F> {
         Elements { cur_elem: F::one(), cur_pow: 0, domain: *self }
     }
 
+    /// Return the elements of the domain, computing and caching them on this thread if they are not yet cached.
+    /// Domains larger than [`MAX_CACHED_DOMAIN_SIZE`] are not cached, and their elements are recomputed on each call.
+    /// The cache can be emptied with [`clear_roots_cache`].
+    pub fn cached_elements(&self) -> Arc<Vec<F>> {
+        if let Some(elements) = self.lookup_cached_elements() {
+            return elements;
+        }
+        let elements: Arc<Vec<F>> = Arc::new(self.par_elements().collect());
+        if self.size() > MAX_CACHED_DOMAIN_SIZE {
+            return elements;
+        }
+        ELEMENTS_CACHE.with(|cache| cache.borrow_mut().insert((TypeId::of::<F>(), self.size), elements.clone()));
+        elements
+    }
+
+    fn lookup_cached_elements(&self) -> Option<Arc<Vec<F>>> {
+        ELEMENTS_CACHE.with(|cache| {
+            cache.borrow().get(&(TypeId::of::<F>(), self.size)).cloned().and_then(|e| e.downcast::<Vec<F>>().ok())
+        })
+    }
+
+    /// Return the `i`-th element of the domain, i.e. `self.group_gen^i`.
+    pub fn element(&self, i: usize) -> F {
+        self.group_gen.pow([i as u64])
+    }
+
+    /// Return the elements of the domain in a single contiguous buffer, whose `i`-th entry is
+    /// `self.group_gen^i` for `0 <= i < self.size()`. See [`Self::roots_into`].
+    pub fn roots_flat(&self) -> Vec<F> {
+        let mut roots = vec![F::zero(); self.size()];
+        self.roots_into(&mut roots);
+        roots
+    }
+
+    /// Write the elements of the domain into `out`, so that `out[i] = self.group_gen^i` for `0 <= i < self.size()`.
+    /// This allows callers to reuse a buffer, such as one in pinned memory for an accelerator.
+    /// Panics if `out.len() != self.size()`.
+    pub fn roots_into(&self, out: &mut [F]) {
+        assert_eq!(out.len(), self.size(), "The output buffer must match the domain size");
+        if self.size() == 1 {
+            out[0] = F::one();
+            return;
+        }
+        // The second half of the elements are the negations of the first half, since `g^{n/2} = -1`.
+        let (lo, hi) = out.split_at_mut(self.size() / 2);
+        lo.copy_from_slice(&self.roots_of_unity(self.group_gen));
+        cfg_iter_mut!(hi).zip(cfg_iter!(lo)).for_each(|(hi, lo)| *hi = -*lo);
+    }
+
+    /// Return a parallel iterator over the elements of the domain, in the same order as `self.elements()`.
+    /// Each parallel job seeds its first element via `self.element(i)`, and computes the rest
+    /// by repeated multiplication.
+    #[cfg(not(feature = "serial"))]
+    pub fn par_elements(&self) -> impl IndexedParallelIterator<Item = F> {
+        let domain = *self;
+        (0..self.size()).into_par_iter().with_min_len(MIN_PARALLEL_CHUNK_SIZE).map_init(
+            || None,
+            move |prev: &mut Option<(usize, F)>, i| {
+                let element = match *prev {
+                    Some((j, prev_element)) if j + 1 == i => prev_element * domain.group_gen,
+                    _ => domain.element(i),
+                };
+                *prev = Some((i, element));
+                element
+            },
+        )
+    }
+
+    /// Return an iterator over the elements of the domain, in the same order as `self.elements()`.
+    /// Without parallelism, the elements are computed sequentially.
+    #[cfg(feature = "serial")]
+    pub fn par_elements(&self) -> Elements<F> {
+        Elements { cur_elem: F::one(), cur_pow: 0, domain: *self }
+    }
+
+    /// Return the coset `offset * self` of this domain, or `None` if `offset` is zero.
+    pub fn get_coset(&self, offset: F) -> Option<CosetDomain<F>> {
+        Some(CosetDomain {
+            domain: *self,
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size]),
+        })
+    }
+
+    /// Return the subgroup of `self` of half the size, consisting of the squares of the elements of `self`.
+    /// Returns `None` if `self` has size 1.
+    pub fn halve(&self) -> Option<Self> {
+        if self.size == 1 { None } else { Self::new(self.size() / 2) }
+    }
+
+    /// Return the domain of twice the size of `self`, which contains `self` as the subgroup of its squares.
+    /// Returns `None` if the doubled size exceeds the two-adicity of the field.
+    pub fn double(&self) -> Option<Self> {
+        Self::new(self.size().checked_mul(2)?)
+    }
+
+    /// Map the evaluations of a polynomial over `self` to its evaluations over `self.halve()`,
+    /// by keeping the evaluations at the even indices, since the `i`-th element of the halved domain
+    /// is the `2i`-th element of `self`.
+    pub fn decimate_evaluations<T: Copy>(&self, evals: &[T]) -> Vec<T> {
+        assert_eq!(evals.len(), self.size(), "The number of evaluations must match the domain size");
+        evals.iter().step_by(2).copied().collect()
+    }
+
+    /// Map the evaluations of a polynomial `p` over `self` to the evaluations of `p` over `self.double()`,
+    /// by interpolating over `self` and evaluating over the doubled domain. The evaluations over `self`
+    /// reappear at the even indices of the result, so `decimate_evaluations` undoes this.
+    ///
+    /// Panics if the doubled size exceeds the two-adicity of the field.
+    pub fn expand_evaluations<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        assert_eq!(evals.len(), self.size(), "The number of evaluations must match the domain size");
+        let doubled = self.double().expect("The doubled domain exceeds the two-adicity of the field");
+        let mut coeffs = self.ifft(evals);
+        doubled.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
     /// The target polynomial is the zero polynomial in our
     /// evaluation domain, so we must perform division over
     /// a coset.
@@ -353,6 +733,29 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 }
 
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Evaluate `polynomial` over the domain with an FFT, returning the evaluations tagged with the domain.
+    /// Polynomials with fewer coefficients than the size of the domain are padded with zeros. Since `g^n = 1`
+    /// for every element `g` of a domain of size `n`, the coefficient of `x^{i + k * n}` is added to that of `x^i`
+    /// when `polynomial` has more coefficients than that.
+    pub fn fft_polynomial(&self, polynomial: &DensePolynomial<F>) -> Evaluations<F> {
+        let size = self.size();
+        let mut coeffs = polynomial.coeffs.get(..size).unwrap_or(&polynomial.coeffs).to_vec();
+        for chunk in polynomial.coeffs.chunks(size).skip(1) {
+            cfg_iter_mut!(coeffs).zip(chunk).for_each(|(c, d)| *c += d);
+        }
+        self.fft_in_place(&mut coeffs);
+        Evaluations::from_vec_and_domain(coeffs, *self)
+    }
+
+    /// Interpolate `evaluations` over the domain with an inverse FFT, returning the polynomial with any
+    /// trailing zeros removed. As in [`Self::ifft`], the evaluations are padded with zeros or truncated to
+    /// the size of the domain, so they should normally be over this domain already.
+    pub fn ifft_evaluations(&self, evaluations: &Evaluations<F>) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec(self.ifft(&evaluations.evaluations))
+    }
+}
+
 impl<F: FftField> EvaluationDomain<F> {
     pub fn precompute_fft(&self) -> FFTPrecomputation<F> {
         execute_with_max_available_threads(|| FFTPrecomputation {
@@ -607,13 +1010,13 @@ impl<F: FftField> EvaluationDomain<F> {
 
             // allocate the return array and start the recursion
             let mut powers = vec![F::zero(); 1 << (log_size - 1)];
-            Self::roots_of_unity_recursive(&mut powers, &log_powers);
+            Self::roots_of_unity_recursive(&mut powers, &log_powers, FftConfig::current().max_parallel_depth());
             powers
         }
     }
 
     #[cfg(not(feature = "serial"))]
-    fn roots_of_unity_recursive(out: &mut [F], log_powers: &[F]) {
+    fn roots_of_unity_recursive(out: &mut [F], log_powers: &[F], max_parallel_depth: usize) {
         assert_eq!(out.len(), 1 << log_powers.len());
         // base case: just compute the powers sequentially,
         // g = log_powers[0], out = [1, g, g^2, ...]
@@ -630,18 +1033,28 @@ impl<F: FftField> EvaluationDomain<F> {
         let (lr_lo, lr_hi) = log_powers.split_at((1 + log_powers.len()) / 2);
         let mut scr_lo = vec![F::default(); 1 << lr_lo.len()];
         let mut scr_hi = vec![F::default(); 1 << lr_hi.len()];
-        // 2. compute each half individually
-        rayon::join(
-            || Self::roots_of_unity_recursive(&mut scr_lo, lr_lo),
-            || Self::roots_of_unity_recursive(&mut scr_hi, lr_hi),
-        );
+        // 2. compute each half individually, in parallel unless the maximum depth is reached
+        if max_parallel_depth == 0 {
+            Self::roots_of_unity_recursive(&mut scr_lo, lr_lo, 0);
+            Self::roots_of_unity_recursive(&mut scr_hi, lr_hi, 0);
+        } else {
+            rayon::join(
+                || Self::roots_of_unity_recursive(&mut scr_lo, lr_lo, max_parallel_depth - 1),
+                || Self::roots_of_unity_recursive(&mut scr_hi, lr_hi, max_parallel_depth - 1),
+            );
+        }
         // 3. recombine halves
         // At this point, out is a blank slice.
-        out.par_chunks_mut(scr_lo.len()).zip(&scr_hi).for_each(|(out_chunk, scr_hi)| {
+        let recombine = |(out_chunk, scr_hi): (&mut [F], &F)| {
             for (out_elem, scr_lo) in out_chunk.iter_mut().zip(&scr_lo) {
                 *out_elem = *scr_hi * scr_lo;
             }
-        });
+        };
+        if max_parallel_depth == 0 {
+            out.chunks_mut(scr_lo.len()).zip(&scr_hi).for_each(recombine);
+        } else {
+            out.par_chunks_mut(scr_lo.len()).zip(&scr_hi).for_each(recombine);
+        }
     }
 
     #[inline(always)]
@@ -671,17 +1084,23 @@ impl<F: FftField> EvaluationDomain<F> {
         max_threads: usize,
         gap: usize,
     ) {
-        cfg_chunks_mut!(xi, chunk_size).for_each(|cxi| {
+        let max_parallel_depth = FftConfig::current().max_parallel_depth();
+        let butterflies = |cxi: &mut [T]| {
             let (lo, hi) = cxi.split_at_mut(gap);
-            // If the chunk is sufficiently big that parallelism helps,
+            // If the chunk is sufficiently big that parallelism helps, and nesting is allowed,
             // we parallelize the butterfly operation within the chunk.
 
-            if gap > MIN_GAP_SIZE_FOR_PARALLELISATION && num_chunks < max_threads {
+            if gap > MIN_GAP_SIZE_FOR_PARALLELISATION && num_chunks < max_threads && max_parallel_depth >= 2 {
                 cfg_iter_mut!(lo).zip(hi).zip(cfg_iter!(roots).step_by(step)).for_each(g);
             } else {
                 lo.iter_mut().zip(hi).zip(roots.iter().step_by(step)).for_each(g);
             }
-        });
+        };
+        if max_parallel_depth == 0 {
+            xi.chunks_mut(chunk_size).for_each(butterflies);
+        } else {
+            cfg_chunks_mut!(xi, chunk_size).for_each(butterflies);
+        }
     }
 
     #[allow(clippy::unnecessary_to_owned)]
@@ -782,6 +1201,10 @@ const MIN_GAP_SIZE_FOR_PARALLELISATION: usize = 1 << 10;
 #[cfg(not(feature = "serial"))]
 const LOG_ROOTS_OF_UNITY_PARALLEL_SIZE: u32 = 7;
 
+/// The number of rows or columns that `fft_in_blocks` gathers at a time. Each gathered run of
+/// `FFT_IN_BLOCKS_TILE_SIZE` elements spans several cache lines.
+const FFT_IN_BLOCKS_TILE_SIZE: usize = 1 << 4;
+
 #[inline]
 pub(super) fn bitrev(a: u64, log_len: u32) -> u64 {
     a.reverse_bits() >> (64 - log_len)
@@ -852,6 +1275,95 @@ pub(crate) fn compute_powers<F: Field>(size: usize, g: F) -> Vec<F> {
     res
 }
 
+/// A multiplicative coset `offset * H` of an evaluation domain `H`.
+/// All operations on the coset account for the offset automatically.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub struct CosetDomain<F: FftField> {
+    /// The underlying subgroup.
+    domain: EvaluationDomain<F>,
+    /// The offset of the coset.
+    offset: F,
+    /// Inverse of the offset.
+    offset_inv: F,
+    /// `offset^self.size()`.
+    offset_pow_size: F,
+}
+
+impl<F: FftField> CosetDomain<F> {
+    /// Return the underlying subgroup of `self`.
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Return the offset of `self`.
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        self.domain.size()
+    }
+
+    /// Return an iterator over the elements of the coset.
+    pub fn elements(&self) -> Elements<F> {
+        Elements { cur_elem: self.offset, cur_pow: 0, domain: self.domain }
+    }
+
+    /// Return the sparse vanishing polynomial `X^self.size - offset^self.size`.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        let coeffs = [(0, -self.offset_pow_size), (self.size(), F::one())];
+        SparsePolynomial::from_coefficients(coeffs)
+    }
+
+    /// This evaluates the vanishing polynomial for this coset at tau.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        self.domain.pow_size(tau) - self.offset_pow_size
+    }
+
+    /// Returns whether `point` is an element of the coset, i.e. whether `point^self.size == offset^self.size`.
+    pub fn contains(&self, point: F) -> bool {
+        self.evaluate_vanishing_polynomial(point).is_zero()
+    }
+
+    /// Evaluate all the lagrange polynomials defined by this coset at the point `tau`.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        // The `i`-th lagrange polynomial of the coset is `L_i(X / offset)`,
+        // where `L_i` is the `i`-th lagrange polynomial of the subgroup.
+        self.domain.evaluate_all_lagrange_coefficients(tau * self.offset_inv)
+    }
+
+    /// Compute an FFT over the coset.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT over the coset, modifying the input vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        execute_with_max_available_threads(|| {
+            EvaluationDomain::distribute_powers(coeffs, self.offset);
+            self.domain.fft_in_place(coeffs);
+        });
+    }
+
+    /// Compute an IFFT over the coset.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT over the coset, modifying the input vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        execute_with_max_available_threads(|| {
+            self.domain.ifft_in_place(evals);
+            EvaluationDomain::distribute_powers(evals, self.offset_inv);
+        });
+    }
+}
+
 /// An iterator over the elements of the domain.
 #[derive(Clone)]
 pub struct Elements<F: FftField> {
@@ -883,6 +1395,11 @@ pub struct FFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> FFTPrecomputation<F> {
+    /// Returns the number of roots of unity in `self`, which is half the size of its domain.
+    pub fn num_roots(&self) -> usize {
+        self.roots.len()
+    }
+
     pub fn to_ifft_precomputation(&self) -> IFFTPrecomputation<F> {
         let mut inverse_roots = self.roots.clone();
         snarkvm_fields::batch_inversion(&mut inverse_roots);
@@ -913,6 +1430,11 @@ pub struct IFFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> IFFTPrecomputation<F> {
+    /// Returns the number of inverse roots of unity in `self`, which is half the size of its domain.
+    pub fn num_roots(&self) -> usize {
+        self.inverse_roots.len()
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { inverse_roots: vec![], domain: *domain }));
@@ -933,10 +1455,10 @@ impl<F: FftField> IFFTPrecomputation<F> {
 mod tests {
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     use crate::fft::domain::FFTOrder;
-    use crate::fft::{DensePolynomial, EvaluationDomain};
+    use crate::fft::{DensePolynomial, DomainError, EvaluationDomain, FftConfig, SparsePolynomial};
     use rand::Rng;
     use snarkvm_curves::bls12_377::Fr;
-    use snarkvm_fields::{FftField, Field, One, Zero};
+    use snarkvm_fields::{FftField, FftParameters, Field, One, Zero};
     use snarkvm_utilities::{TestRng, Uniform};
 
     #[test]
@@ -952,6 +1474,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn coset_vanishing_polynomial_evaluation() {
+        let rng = &mut TestRng::default();
+        for coeffs in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(coeffs).unwrap();
+            let offset = Fr::rand(rng);
+            let z = domain.get_coset(offset).unwrap().vanishing_polynomial();
+            for _ in 0..100 {
+                let point = rng.gen();
+                assert_eq!(z.evaluate(point), domain.evaluate_coset_vanishing_polynomial(offset, point))
+            }
+        }
+    }
+
     #[test]
     fn vanishing_polynomial_vanishes_on_domain() {
         for coeffs in 0..1000 {
@@ -984,6 +1520,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cached_elements() {
+        for log_size in [0, 1, 5, 10] {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let expected: Vec<Fr> = (0..domain.size()).map(|i| domain.element(i)).collect();
+            assert_eq!(*domain.cached_elements(), expected);
+            assert!(domain.lookup_cached_elements().is_some());
+            assert_eq!(domain.elements().collect::<Vec<_>>(), expected);
+        }
+        super::clear_roots_cache();
+        let domain = EvaluationDomain::<Fr>::new(4).unwrap();
+        assert!(domain.lookup_cached_elements().is_none());
+        // Iterating over the elements does not populate the cache.
+        assert_eq!(domain.elements().count(), 4);
+        assert!(domain.lookup_cached_elements().is_none());
+        // Domains larger than the limit are not cached.
+        let domain = EvaluationDomain::<Fr>::new(2 * super::MAX_CACHED_DOMAIN_SIZE).unwrap();
+        assert_eq!(domain.cached_elements().len(), domain.size());
+        assert!(domain.lookup_cached_elements().is_none());
+    }
+
+    #[test]
+    fn fft_polynomial() {
+        let mut rng = TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        for degree in [0, 5, 15, 16, 40] {
+            let polynomial = DensePolynomial::<Fr>::rand(degree, &mut rng);
+            let evaluations = domain.fft_polynomial(&polynomial);
+            assert_eq!(evaluations.domain(), domain);
+            for (element, evaluation) in domain.elements().zip(&evaluations.evaluations) {
+                assert_eq!(polynomial.evaluate(element), *evaluation);
+            }
+            if degree < domain.size() {
+                assert_eq!(domain.ifft_evaluations(&evaluations), polynomial);
+            }
+        }
+        let zero = DensePolynomial::<Fr>::zero();
+        assert!(domain.ifft_evaluations(&domain.fft_polynomial(&zero)).is_zero());
+    }
+
+    #[test]
+    fn roots_flat() {
+        for log_size in [0, 1, 5, 10] {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let expected: Vec<Fr> = domain.elements().collect();
+            assert_eq!(domain.roots_flat(), expected);
+
+            let mut buffer = vec![Fr::zero(); domain.size()];
+            domain.roots_into(&mut buffer);
+            assert_eq!(buffer, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The output buffer must match the domain size")]
+    fn roots_into_wrong_size() {
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        domain.roots_into(&mut [Fr::zero(); 4]);
+    }
+
+    #[test]
+    fn par_elements_contents() {
+        for log_size in [0, 1, 5, 10, 14] {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let elements: Vec<Fr> = domain.elements().collect();
+            for (i, element) in elements.iter().enumerate() {
+                assert_eq!(*element, domain.element(i));
+            }
+            #[cfg(not(feature = "serial"))]
+            let par_elements: Vec<Fr> = rayon::prelude::ParallelIterator::collect(domain.par_elements());
+            #[cfg(feature = "serial")]
+            let par_elements: Vec<Fr> = domain.par_elements().collect();
+            assert_eq!(elements, par_elements);
+        }
+    }
+
     /// Test that lagrange interpolation for a random polynomial at a random point works.
     #[test]
     fn non_systematic_lagrange_coefficients_test() {
@@ -1009,6 +1621,245 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fft_sparse() {
+        let mut rng = TestRng::default();
+        for log_size in [0, 1, 4, 10] {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for num_terms in [0, 1, 3] {
+                // Allow exponents larger than the domain size.
+                let p = SparsePolynomial::<Fr>::rand(num_terms, 3 << log_size, &mut rng);
+                let dense = DensePolynomial::from(p.clone());
+                assert_eq!(domain.fft_sparse(&p), dense.evaluate_over_domain(domain).evaluations);
+            }
+            assert_eq!(domain.fft_sparse(&domain.vanishing_polynomial()), vec![Fr::zero(); domain.size()]);
+        }
+    }
+
+    #[test]
+    fn distribute_powers() {
+        let mut rng = TestRng::default();
+        for len in [0, 1, 10, 1000, 5000] {
+            let g = Fr::rand(&mut rng);
+            let coeffs: Vec<Fr> = (0..len).map(|_| Fr::rand(&mut rng)).collect();
+
+            let mut expected = coeffs.clone();
+            let mut pow = Fr::one();
+            for coeff in expected.iter_mut() {
+                *coeff *= pow;
+                pow *= g;
+            }
+
+            let mut actual = coeffs;
+            EvaluationDomain::distribute_powers(&mut actual, g);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn coset_domain_is_consistent() {
+        let mut rng = TestRng::default();
+        for domain_dimension in 1..8 {
+            let domain_size = 1 << domain_dimension;
+            let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
+            assert!(domain.get_coset(Fr::zero()).is_none());
+            let coset = domain.get_coset(Fr::rand(&mut rng)).unwrap();
+
+            let elements: Vec<Fr> = coset.elements().collect();
+            assert_eq!(elements.len(), domain_size);
+            let z = coset.vanishing_polynomial();
+            for element in &elements {
+                assert!(z.evaluate(*element).is_zero());
+                assert!(coset.evaluate_vanishing_polynomial(*element).is_zero());
+            }
+
+            // The FFT over the coset evaluates the polynomial at the coset elements.
+            let random_polynomial = DensePolynomial::<Fr>::rand(domain_size - 1, &mut rng);
+            let evaluations = coset.fft(random_polynomial.coeffs());
+            for (element, evaluation) in elements.iter().zip(&evaluations) {
+                assert_eq!(random_polynomial.evaluate(*element), *evaluation);
+            }
+            assert_eq!(coset.ifft(&evaluations), random_polynomial.coeffs);
+
+            // Lagrange interpolation over the coset recovers the evaluation at a random point.
+            let random_point = Fr::rand(&mut rng);
+            let lagrange_coefficients = coset.evaluate_all_lagrange_coefficients(random_point);
+            let interpolated_evaluation: Fr = lagrange_coefficients.iter().zip(&evaluations).map(|(l, e)| *l * e).sum();
+            assert_eq!(random_polynomial.evaluate(random_point), interpolated_evaluation);
+        }
+    }
+
+    #[test]
+    fn try_new_errors() {
+        let two_adicity = <Fr as FftField>::FftParameters::TWO_ADICITY;
+        assert!(EvaluationDomain::<Fr>::try_new(1 << two_adicity).is_ok());
+        assert_eq!(
+            EvaluationDomain::<Fr>::try_new((1 << two_adicity) + 1),
+            Err(DomainError::SizeExceedsTwoAdicity {
+                num_coeffs: (1 << two_adicity) + 1,
+                log_size: two_adicity + 1,
+                two_adicity
+            })
+        );
+        assert_eq!(
+            EvaluationDomain::<Fr>::try_new(usize::MAX),
+            Err(DomainError::SizeOverflow { num_coeffs: usize::MAX })
+        );
+        assert!(EvaluationDomain::<Fr>::new(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn new_for_size_and_field() {
+        use snarkvm_curves::bls12_377::Fq;
+
+        // The scalar field of BLS12-377 has two-adicity 47, while its base field has two-adicity 46.
+        let size = 1 << 47;
+        assert_eq!(EvaluationDomain::<Fr>::new_for_size_and_field(size).unwrap().size(), size);
+        let error = EvaluationDomain::<Fq>::new_for_size_and_field(size).unwrap_err();
+        assert_eq!(
+            error,
+            DomainError::UnsupportedByField {
+                field: core::any::type_name::<Fq>(),
+                size,
+                log_size: 47,
+                two_adicity: 46
+            }
+        );
+        assert!(error.to_string().contains("two-adicity 46"));
+        assert_eq!(
+            EvaluationDomain::<Fr>::new_for_size_and_field(usize::MAX),
+            Err(DomainError::SizeOverflow { num_coeffs: usize::MAX })
+        );
+    }
+
+    #[test]
+    fn size_for_degree() {
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(0), Some(1));
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(1), Some(2));
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(7), Some(8));
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(8), Some(16));
+
+        let max_degree = EvaluationDomain::<Fr>::max_supported_degree();
+        assert_eq!(max_degree, (1 << <Fr as FftField>::FftParameters::TWO_ADICITY) - 1);
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(max_degree), Some(max_degree + 1));
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(max_degree + 1), None);
+        assert_eq!(EvaluationDomain::<Fr>::size_for_degree(usize::MAX), None);
+    }
+
+    #[test]
+    fn fft_with_bounded_parallel_depth() {
+        let mut rng = TestRng::default();
+        assert_eq!(FftConfig::default().max_parallel_depth(), usize::MAX);
+        // The roots of unity are computed recursively for domains larger than `2^8`.
+        let domain = EvaluationDomain::<Fr>::new(1 << 12).unwrap();
+        let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let expected = domain.fft(&coeffs);
+        let expected_roots = domain.roots_of_unity(domain.group_gen);
+
+        for depth in [0, 1, 2] {
+            let previous = FftConfig::default().with_max_parallel_depth(depth).install();
+            assert_eq!(FftConfig::current().max_parallel_depth(), depth);
+            let evaluations = domain.fft(&coeffs);
+            let roots = domain.roots_of_unity(domain.group_gen);
+            previous.install();
+
+            assert_eq!(evaluations, expected, "depth = {depth}");
+            assert_eq!(domain.ifft(&evaluations), coeffs, "depth = {depth}");
+            assert_eq!(roots, expected_roots, "depth = {depth}");
+        }
+    }
+
+    #[cfg(not(feature = "serial"))]
+    #[test]
+    fn fft_is_independent_of_thread_count() {
+        let mut rng = TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(1 << 12).unwrap();
+        let coset = domain.get_coset(Fr::multiplicative_generator()).unwrap();
+        let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let expected = (domain.fft(&coeffs), domain.ifft(&coeffs), coset.fft(&coeffs));
+
+        for num_threads in [1, 2, 3, 8] {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+            let results = pool.install(|| (domain.fft(&coeffs), domain.ifft(&coeffs), coset.fft(&coeffs)));
+            assert!(results == expected, "num_threads = {num_threads}");
+        }
+    }
+
+    #[test]
+    fn fft_in_blocks() {
+        let mut rng = TestRng::default();
+        for log_size in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = domain.fft(&coeffs);
+            let pc = domain.precompute_fft();
+            for log_block in 0..=log_size + 1 {
+                let mut blocked = coeffs.clone();
+                domain.fft_in_blocks(&mut blocked, 1 << log_block);
+                assert_eq!(blocked, expected, "log_size = {log_size}, log_block = {log_block}");
+
+                let mut blocked = coeffs.clone();
+                domain.fft_in_blocks_with_pc(&mut blocked, 1 << log_block, &pc);
+                assert_eq!(blocked, expected, "log_size = {log_size}, log_block = {log_block}");
+            }
+        }
+    }
+
+    #[test]
+    fn halve_and_double() {
+        let mut rng = TestRng::default();
+        assert!(EvaluationDomain::<Fr>::new(1).unwrap().halve().is_none());
+        let max_size = 1 << <Fr as FftField>::FftParameters::TWO_ADICITY;
+        assert!(EvaluationDomain::<Fr>::new(max_size).unwrap().double().is_none());
+
+        for log_size in 1..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let halved = domain.halve().unwrap();
+            let doubled = domain.double().unwrap();
+            assert_eq!(halved.size(), domain.size() / 2);
+            assert_eq!(doubled.size(), domain.size() * 2);
+            assert_eq!(halved.double().unwrap(), domain);
+            assert_eq!(doubled.halve().unwrap(), domain);
+
+            // The elements of the halved domain are the even-indexed elements of the domain.
+            let elements = domain.elements().collect::<Vec<_>>();
+            assert_eq!(domain.decimate_evaluations(&elements), halved.elements().collect::<Vec<_>>());
+
+            // Expanding then decimating recovers the evaluations.
+            let evals = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let expanded = domain.expand_evaluations(&evals);
+            assert_eq!(doubled.decimate_evaluations(&expanded), evals);
+            // The expanded evaluations are those of the interpolant over the doubled domain.
+            let p = DensePolynomial::from_coefficients_vec(domain.ifft(&evals));
+            assert_eq!(expanded, doubled.elements().map(|e| p.evaluate(e)).collect::<Vec<_>>());
+
+            // Decimating then expanding recovers the evaluations of a polynomial that fits in the halved domain.
+            let p = DensePolynomial::<Fr>::rand(halved.size() - 1, &mut rng);
+            let evals = domain.fft(&p.coeffs);
+            assert_eq!(halved.expand_evaluations(&domain.decimate_evaluations(&evals)), evals);
+        }
+    }
+
+    #[test]
+    fn domain_contains() {
+        let mut rng = TestRng::default();
+        for domain_dimension in 1..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << domain_dimension).unwrap();
+            let coset = domain.get_coset(Fr::multiplicative_generator()).unwrap();
+            for element in domain.elements() {
+                assert!(domain.contains(element));
+                assert!(!coset.contains(element));
+            }
+            for element in coset.elements() {
+                assert!(coset.contains(element));
+                assert!(!domain.contains(element));
+            }
+            let outside = domain.sample_element_outside_domain(&mut rng);
+            assert!(!domain.contains(outside));
+            assert!(!domain.contains(Fr::zero()));
+        }
+    }
+
     /// Test that lagrange coefficients for a point in the domain is correct.
     #[test]
     fn systematic_lagrange_coefficients_test() {
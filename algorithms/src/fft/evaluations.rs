@@ -14,7 +14,7 @@
 
 //! A polynomial represented in evaluations form.
 
-use crate::fft::{DensePolynomial, EvaluationDomain};
+use crate::fft::{DensePolynomial, DomainError, EvaluationDomain};
 #[cfg(feature = "serial")]
 use itertools::Itertools;
 #[cfg(not(feature = "serial"))]
@@ -47,7 +47,8 @@ impl<F: PrimeField> Evaluations<F> {
         DensePolynomial::from_coefficients_vec(self.domain.ifft(&self.evaluations))
     }
 
-    /// Interpolate a polynomial from a list of evaluations
+    /// Interpolate a polynomial from a list of evaluations, reusing the inverse roots in `pc`.
+    /// The result is the same as that of `self.interpolate_by_ref()`.
     pub fn interpolate_with_pc_by_ref(&self, pc: &IFFTPrecomputation<F>) -> DensePolynomial<F> {
         let mut evals = self.evaluations.clone();
         evals.resize(self.domain.size(), F::zero());
@@ -62,7 +63,8 @@ impl<F: PrimeField> Evaluations<F> {
         DensePolynomial::from_coefficients_vec(evals)
     }
 
-    /// Interpolate a polynomial from a list of evaluations
+    /// Interpolate a polynomial from a list of evaluations, reusing the inverse roots in `pc`.
+    /// The result is the same as that of `self.interpolate()`.
     pub fn interpolate_with_pc(self, pc: &IFFTPrecomputation<F>) -> DensePolynomial<F> {
         let Self { evaluations: mut evals, domain } = self;
         evals.resize(self.domain.size(), F::zero());
@@ -70,6 +72,23 @@ impl<F: PrimeField> Evaluations<F> {
         DensePolynomial::from_coefficients_vec(evals)
     }
 
+    /// Interpolate a polynomial that is known to have degree at most `degree_bound`, by truncating the result
+    /// of the IFFT to its first `degree_bound + 1` coefficients, which skips the scan for trailing zeros above them.
+    ///
+    /// In debug builds, this panics if any of the truncated coefficients is nonzero, which means that
+    /// the evaluations do not come from a polynomial of degree at most `degree_bound`.
+    pub fn interpolate_bounded(self, degree_bound: usize) -> DensePolynomial<F> {
+        let Self { evaluations: mut evals, domain } = self;
+        domain.ifft_in_place(&mut evals);
+        let len = degree_bound.saturating_add(1).min(evals.len());
+        debug_assert!(
+            evals[len..].iter().all(|c| c.is_zero()),
+            "The interpolated polynomial has degree greater than {degree_bound}"
+        );
+        evals.truncate(len);
+        DensePolynomial::from_coefficients_vec(evals)
+    }
+
     /// Returns the evaluations of `self`.
     pub fn evaluations(&self) -> &[F] {
         &self.evaluations
@@ -87,6 +106,87 @@ impl<F: PrimeField> Evaluations<F> {
     pub fn evaluate_with_coeffs(&self, lagrange_coefficients_at_point: &[F]) -> F {
         cfg_iter!(self.evaluations).zip_eq(lagrange_coefficients_at_point).map(|(a, b)| *a * b).sum()
     }
+
+    /// Square each evaluation of `self`, producing the evaluations of `p^2` over the same domain.
+    ///
+    /// The result only interpolates to `p^2` if `2 * deg(p) < self.domain().size()`;
+    /// otherwise the interpolated polynomial is `p^2` reduced modulo the vanishing polynomial of the domain.
+    pub fn square(&self) -> Self {
+        let evaluations = cfg_iter!(self.evaluations).map(|e| e.square()).collect();
+        Self { evaluations, domain: self.domain }
+    }
+
+    /// Raise each evaluation of `self` to the power `exp`, producing the evaluations of `p^exp` over the same domain.
+    ///
+    /// The result only interpolates to `p^exp` if `exp * deg(p) < self.domain().size()`;
+    /// otherwise the interpolated polynomial is `p^exp` reduced modulo the vanishing polynomial of the domain.
+    pub fn pow(&self, exp: u64) -> Self {
+        let evaluations = cfg_iter!(self.evaluations).map(|e| e.pow([exp])).collect();
+        Self { evaluations, domain: self.domain }
+    }
+
+    /// Apply `f` to each evaluation of `self`, producing evaluations over the same domain.
+    pub fn map(&self, f: impl Fn(F) -> F + Sync + Send) -> Self {
+        let evaluations = cfg_iter!(self.evaluations).map(|e| f(*e)).collect();
+        Self { evaluations, domain: self.domain }
+    }
+
+    /// Apply `f` to each pair of aligned evaluations of `self` and `other`, producing evaluations over the same domain.
+    /// This allows gate identities to be expressed directly over evaluations, e.g. `a.zip_map(&b, |a, b| a * b - a)`.
+    ///
+    /// Panics if the domains of `self` and `other` are unequal; see [`Self::try_zip_map`] for a fallible version.
+    pub fn zip_map(&self, other: &Self, f: impl Fn(F, F) -> F + Sync + Send) -> Self {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        self.try_zip_map(other, f).unwrap()
+    }
+
+    /// Apply `f` to each pair of aligned evaluations of `self` and `other`, as [`Self::zip_map`] does.
+    ///
+    /// Returns `DomainError::DomainMismatch` if the domains of `self` and `other` are unequal.
+    pub fn try_zip_map(&self, other: &Self, f: impl Fn(F, F) -> F + Sync + Send) -> Result<Self, DomainError> {
+        if self.domain != other.domain {
+            return Err(DomainError::DomainMismatch { expected: self.domain.size(), found: other.domain.size() });
+        }
+        let evaluations = cfg_iter!(self.evaluations).zip_eq(&other.evaluations).map(|(a, b)| f(*a, *b)).collect();
+        Ok(Self { evaluations, domain: self.domain })
+    }
+
+    /// Cyclically shift the evaluations of `p` by `k` positions, producing the evaluations of `p(g^k * X)`
+    /// over the same domain, where `g` is the generator of the domain. That is, the `i`-th evaluation of the result
+    /// is the `(i + k) mod n`-th evaluation of `self`. A negative `k` shifts in the other direction.
+    ///
+    /// Panics if the number of evaluations does not match the size of the domain.
+    pub fn rotate(&self, k: isize) -> Self {
+        let size = self.domain.size();
+        assert_eq!(self.evaluations.len(), size, "The number of evaluations must match the domain size");
+        let mut evaluations = self.evaluations.clone();
+        evaluations.rotate_left(k.rem_euclid(size as isize) as usize);
+        Self { evaluations, domain: self.domain }
+    }
+
+    /// Perform one round of FRI folding with the challenge `r`, producing the evaluations of
+    /// `f'(X^2) = (f(X) + f(-X)) / 2 + r * (f(X) - f(-X)) / (2X)` over `self.domain().halve()`.
+    /// That is, if `f(X) = f_even(X^2) + X * f_odd(X^2)`, then `f' = f_even + r * f_odd`.
+    ///
+    /// Since `-g^i = g^{i + n/2}`, both evaluations for the `i`-th element of the halved domain are read
+    /// from `self`, and the `1/X` factors are the precomputed powers of the inverse generator of the domain.
+    ///
+    /// Panics if the number of evaluations does not match the size of the domain, or if the domain has size 1.
+    pub fn fold_in_half(&self, r: F) -> Self {
+        let size = self.domain.size();
+        assert_eq!(self.evaluations.len(), size, "The number of evaluations must match the domain size");
+        let domain = self.domain.halve().expect("Cannot fold evaluations over a domain of size 1");
+
+        let (lo, hi) = self.evaluations.split_at(size / 2);
+        let x_inv = self.domain.roots_of_unity(self.domain.group_gen_inv);
+        let half = F::half();
+        let evaluations = cfg_iter!(lo)
+            .zip(hi)
+            .zip(&x_inv)
+            .map(|((f_x, f_minus_x), x_inv)| half * (*f_x + f_minus_x + r * (*f_x - f_minus_x) * x_inv))
+            .collect();
+        Self { evaluations, domain }
+    }
 }
 
 impl<F: PrimeField> std::ops::Index<usize> for Evaluations<F> {
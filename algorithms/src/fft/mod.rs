@@ -16,13 +16,23 @@
 //! including FFTs.
 
 pub mod domain;
-pub use domain::EvaluationDomain;
+pub use domain::{CosetDomain, DomainError, EvaluationDomain, FftConfig};
 
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod planner;
+pub use planner::FftPlanner;
+
 pub mod polynomial;
-pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
+pub use polynomial::{
+    BivariatePolynomial,
+    DensePolynomial,
+    MultilinearPolynomial,
+    Polynomial,
+    PolynomialError,
+    SparsePolynomial,
+};
 
 #[cfg(test)]
 mod tests;
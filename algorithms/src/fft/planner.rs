@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A planner that precomputes the roots of unity for all of the domains used in a proving session.
+
+use crate::fft::{
+    domain::{FFTPrecomputation, IFFTPrecomputation},
+    DomainCoeff,
+    DomainError,
+    EvaluationDomain,
+};
+use snarkvm_fields::FftField;
+use snarkvm_utilities::execute_with_max_available_threads;
+
+use std::collections::BTreeMap;
+
+/// The domain of a given size, with the roots of unity needed by its FFTs and IFFTs.
+#[derive(Clone, Debug)]
+struct Plan<F: FftField> {
+    domain: EvaluationDomain<F>,
+    fft_precomputation: FFTPrecomputation<F>,
+    ifft_precomputation: IFFTPrecomputation<F>,
+}
+
+/// Precomputes the roots of unity for every domain size that is used in a proving session, so that FFTs and IFFTs
+/// over a domain of a previously seen size do not recompute them, as [`EvaluationDomain::fft_in_place`] does.
+///
+/// The roots are computed once, for the largest planned domain, and the roots of each smaller domain are
+/// the subsequence of every `k`-th root. A domain of size `n > 1` holds `n / 2` roots and `n / 2` inverse roots,
+/// so a planner for the domain sizes `n_1, ..., n_k` holds `n_1 + ... + n_k` field elements in total,
+/// which is less than twice the size of the largest domain, since the sizes are distinct powers of two.
+#[derive(Clone, Debug)]
+pub struct FftPlanner<F: FftField> {
+    plans: BTreeMap<usize, Plan<F>>,
+}
+
+impl<F: FftField> FftPlanner<F> {
+    /// Plan the domains that are large enough for each number of coefficients in `sizes`,
+    /// returning an error if no such domain exists over `F` for one of them.
+    pub fn new(sizes: impl IntoIterator<Item = usize>) -> Result<Self, DomainError> {
+        let domains = sizes
+            .into_iter()
+            .map(|size| EvaluationDomain::try_new(size).map(|domain| (domain.size(), domain)))
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        let largest = match domains.values().next_back() {
+            Some(domain) => domain,
+            None => return Ok(Self { plans: BTreeMap::new() }),
+        };
+        let (fft_precomputation, ifft_precomputation) = execute_with_max_available_threads(|| {
+            let fft_precomputation = largest.precompute_fft();
+            let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
+            (fft_precomputation, ifft_precomputation)
+        });
+
+        let plans = domains
+            .iter()
+            .map(|(size, domain)| {
+                // Each domain is a subgroup of the largest domain, so these always succeed.
+                let plan = Plan {
+                    domain: *domain,
+                    fft_precomputation: fft_precomputation.precomputation_for_subdomain(domain).unwrap().into_owned(),
+                    ifft_precomputation: ifft_precomputation.precomputation_for_subdomain(domain).unwrap().into_owned(),
+                };
+                (*size, plan)
+            })
+            .collect();
+        Ok(Self { plans })
+    }
+
+    /// Returns the planned domain that is large enough for `num_coeffs` coefficients,
+    /// or `None` if the size of that domain was not planned.
+    pub fn domain(&self, num_coeffs: usize) -> Option<EvaluationDomain<F>> {
+        self.plan(num_coeffs).map(|plan| plan.domain)
+    }
+
+    /// Returns the planned domain sizes, in increasing order.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.plans.keys().copied()
+    }
+
+    /// Returns the number of field elements held by `self`, which is the sum of the planned domain sizes,
+    /// except that a domain of size 1 needs no roots.
+    pub fn num_precomputed_elements(&self) -> usize {
+        self.plans.values().map(|plan| plan.fft_precomputation.num_roots() + plan.ifft_precomputation.num_roots()).sum()
+    }
+
+    /// Returns the FFT precomputation for the planned domain of `domain.size()`, for use with the
+    /// `_with_pc` methods of [`EvaluationDomain`].
+    pub fn fft_precomputation(&self, domain: &EvaluationDomain<F>) -> Option<&FFTPrecomputation<F>> {
+        self.plans.get(&domain.size()).map(|plan| &plan.fft_precomputation)
+    }
+
+    /// Returns the IFFT precomputation for the planned domain of `domain.size()`, for use with the
+    /// `_with_pc` methods of [`EvaluationDomain`] and [`crate::fft::Evaluations`].
+    pub fn ifft_precomputation(&self, domain: &EvaluationDomain<F>) -> Option<&IFFTPrecomputation<F>> {
+        self.plans.get(&domain.size()).map(|plan| &plan.ifft_precomputation)
+    }
+
+    /// Compute an FFT over `domain` in place, as [`EvaluationDomain::fft_in_place`] does.
+    /// The planned roots are used if the size of `domain` was planned, and are computed otherwise.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, domain: &EvaluationDomain<F>, coeffs: &mut Vec<T>) {
+        match self.fft_precomputation(domain) {
+            Some(pc) => execute_with_max_available_threads(|| {
+                coeffs.resize(domain.size(), T::zero());
+                domain.in_order_fft_in_place_with_pc(coeffs, pc);
+            }),
+            None => domain.fft_in_place(coeffs),
+        }
+    }
+
+    /// Compute an IFFT over `domain` in place, as [`EvaluationDomain::ifft_in_place`] does.
+    /// The planned inverse roots are used if the size of `domain` was planned, and are computed otherwise.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, domain: &EvaluationDomain<F>, evals: &mut Vec<T>) {
+        match self.ifft_precomputation(domain) {
+            Some(pc) => execute_with_max_available_threads(|| {
+                evals.resize(domain.size(), T::zero());
+                domain.in_order_ifft_in_place_with_pc(evals, pc);
+            }),
+            None => domain.ifft_in_place(evals),
+        }
+    }
+
+    fn plan(&self, num_coeffs: usize) -> Option<&Plan<F>> {
+        self.plans.get(&EvaluationDomain::<F>::compute_size_of_domain(num_coeffs)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FftPlanner;
+    use crate::fft::{DensePolynomial, EvaluationDomain};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn planned_ffts_match_unplanned_ffts() {
+        let rng = &mut TestRng::default();
+        let planner = FftPlanner::<Fr>::new([3, 1, 64, 16, 16, 20]).unwrap();
+        assert_eq!(planner.sizes().collect::<Vec<_>>(), vec![1, 4, 16, 32, 64]);
+        assert_eq!(planner.num_precomputed_elements(), 4 + 16 + 32 + 64);
+
+        for size in [1, 4, 16, 32, 64, 128] {
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            assert_eq!(planner.domain(size), (size <= 64).then_some(domain));
+
+            let p = DensePolynomial::<Fr>::rand(size - 1, rng);
+            let mut evals = p.coeffs.clone();
+            planner.fft_in_place(&domain, &mut evals);
+            assert_eq!(evals, domain.fft(&p.coeffs));
+            planner.ifft_in_place(&domain, &mut evals);
+            assert_eq!(evals, p.coeffs);
+        }
+
+        let empty = FftPlanner::<Fr>::new([]).unwrap();
+        assert_eq!(empty.sizes().count(), 0);
+        assert!(FftPlanner::<Fr>::new([usize::MAX]).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bivariate polynomial represented by its coefficient rows in `y`.
+
+use crate::fft::DensePolynomial;
+use snarkvm_fields::Field;
+use snarkvm_utilities::{cfg_iter, serialize::*};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Stores a bivariate polynomial `p(x, y) = sum_j p_j(x) * y^j` by its rows `p_j`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BivariatePolynomial<F: Field> {
+    /// The coefficient of `y^j`, as a polynomial in `x`, is stored at location `j` in `self.rows`.
+    rows: Vec<DensePolynomial<F>>,
+}
+
+impl<F: Field> BivariatePolynomial<F> {
+    /// Constructs the bivariate polynomial with coefficient `rows[j]` for `y^j`.
+    pub fn from_rows(rows: Vec<DensePolynomial<F>>) -> Self {
+        Self { rows }
+    }
+
+    /// Returns the rows of `self`, where the `j`-th row is the coefficient of `y^j`.
+    pub fn rows(&self) -> &[DensePolynomial<F>] {
+        &self.rows
+    }
+
+    /// Returns the univariate polynomial `p(x, y)` in `y` for the fixed `x`,
+    /// whose coefficient of `y^j` is the evaluation of the `j`-th row at `x`.
+    pub fn evaluate_in_x(&self, x: F) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec(cfg_iter!(self.rows).map(|row| row.evaluate(x)).collect())
+    }
+
+    /// Returns the univariate polynomial `p(x, y)` in `x` for the fixed `y`, that is `sum_j y^j * p_j(x)`.
+    pub fn evaluate_in_y(&self, y: F) -> DensePolynomial<F> {
+        let num_coeffs = self.rows.iter().map(|row| row.coeffs.len()).max().unwrap_or(0);
+        let mut coeffs = vec![F::zero(); num_coeffs];
+        let mut power = F::one();
+        for row in &self.rows {
+            coeffs.iter_mut().zip(&row.coeffs).for_each(|(c, r)| *c += power * r);
+            power *= y;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// Evaluates `self` at `(x, y)`.
+    pub fn evaluate(&self, x: F, y: F) -> F {
+        self.evaluate_in_x(x).evaluate(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BivariatePolynomial;
+    use crate::fft::DensePolynomial;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{Field, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn evaluate() {
+        let rng = &mut TestRng::default();
+        let rows = (0..5).map(|i| DensePolynomial::<Fr>::rand(3 * i, rng)).collect::<Vec<_>>();
+        let p = BivariatePolynomial::from_rows(rows.clone());
+        let (x, y) = (Fr::rand(rng), Fr::rand(rng));
+
+        let expected = rows.iter().enumerate().map(|(j, row)| row.evaluate(x) * y.pow([j as u64])).sum::<Fr>();
+        assert_eq!(p.evaluate(x, y), expected);
+        assert_eq!(p.evaluate_in_x(x).evaluate(y), expected);
+        assert_eq!(p.evaluate_in_y(y).evaluate(x), expected);
+    }
+
+    #[test]
+    fn empty() {
+        let p = BivariatePolynomial::<Fr>::from_rows(vec![]);
+        assert!(p.evaluate_in_x(Fr::zero()).is_zero());
+        assert!(p.evaluate_in_y(Fr::zero()).is_zero());
+        assert_eq!(p.evaluate(Fr::zero(), Fr::zero()), Fr::zero());
+    }
+}
@@ -14,9 +14,9 @@
 
 //! A polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::fft::{EvaluationDomain, Evaluations, Polynomial, PolynomialError};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, cfg_reduce_with, serialize::*};
 
 use rand::Rng;
 use std::{
@@ -32,8 +32,13 @@ use rayon::prelude::*;
 
 use super::PolyMultiplier;
 
+/// [`DensePolynomial::evaluate_at_domain_via_fft_or_horner`] evaluates a polynomial pointwise by Horner's method,
+/// rather than by an FFT, when it has at most `HORNER_EVALUATION_CROSSOVER * log2(domain.size())` coefficients.
+pub const HORNER_EVALUATION_CROSSOVER: usize = 2;
+
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
 #[must_use]
 pub struct DensePolynomial<F: Field> {
     /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
@@ -66,9 +71,26 @@ impl<F: Field> DensePolynomial<F> {
         self.coeffs.is_empty() || self.coeffs.iter().all(|coeff| coeff.is_zero())
     }
 
+    /// Checks if the given polynomial is the constant `1`, ignoring any trailing zero coefficients.
+    pub fn is_one(&self) -> bool {
+        matches!(self.coeffs.split_first(), Some((c, rest)) if c.is_one() && rest.iter().all(|c| c.is_zero()))
+    }
+
+    /// Checks if the given polynomial is constant (including zero), ignoring any trailing zero coefficients.
+    pub fn is_constant(&self) -> bool {
+        self.coeffs.iter().skip(1).all(|coeff| coeff.is_zero())
+    }
+
     /// Constructs a new polynomial from a list of coefficients.
+    /// Trailing zeros are removed before copying, so only the remaining coefficients are allocated.
     pub fn from_coefficients_slice(coeffs: &[F]) -> Self {
-        Self::from_coefficients_vec(coeffs.to_vec())
+        let len = coeffs.iter().rposition(|c| !c.is_zero()).map_or(0, |i| i + 1);
+        Self { coeffs: coeffs[..len].to_vec() }
+    }
+
+    /// Constructs a new polynomial from an iterator over coefficients, removing any trailing zeros.
+    pub fn from_coefficients_iter<I: IntoIterator<Item = F>>(coeffs: I) -> Self {
+        Self::from_coefficients_vec(coeffs.into_iter().collect())
     }
 
     /// Constructs a new polynomial from a list of coefficients.
@@ -83,6 +105,18 @@ impl<F: Field> DensePolynomial<F> {
         Self { coeffs }
     }
 
+    /// Constructs a new polynomial from a list of coefficients, locating the trailing zeros in parallel.
+    /// The result is identical to that of [`Self::from_coefficients_vec`].
+    pub fn from_coefficients_vec_parallel(mut coeffs: Vec<F>) -> Self {
+        #[cfg(not(feature = "serial"))]
+        let last_nonzero = coeffs.par_iter().position_last(|c| !c.is_zero());
+        #[cfg(feature = "serial")]
+        let last_nonzero = coeffs.iter().rposition(|c| !c.is_zero());
+
+        coeffs.truncate(last_nonzero.map_or(0, |i| i + 1));
+        Self { coeffs }
+    }
+
     /// Returns the degree of the polynomial.
     pub fn degree(&self) -> usize {
         if self.is_zero() {
@@ -102,7 +136,9 @@ impl<F: Field> DensePolynomial<F> {
         }
         let mut powers_of_point = vec![F::one()];
         let mut cur = point;
-        for _ in 0..self.degree() {
+        // Iterate over the coefficients rather than up to `self.degree()`, so that padded polynomials,
+        // such as those produced for hiding commitments, can also be evaluated.
+        for _ in 1..self.coeffs.len() {
             powers_of_point.push(cur);
             cur *= point;
         }
@@ -111,6 +147,12 @@ impl<F: Field> DensePolynomial<F> {
         crate::cfg_reduce!(mapping, || zero, |a, b| a + b)
     }
 
+    /// Returns a closure that evaluates `self` at a given point using Horner's rule, borrowing the coefficients
+    /// of `self` rather than cloning them. This is convenient for passing `self` to code that expects an `Fn(F) -> F`.
+    pub fn evaluator(&self) -> impl Fn(F) -> F + '_ {
+        move |point| self.coeffs.iter().rev().fold(F::zero(), |acc, coeff| acc * point + coeff)
+    }
+
     /// Outputs a polynomial of degree `d` where each coefficient is sampled uniformly at random
     /// from the field `F`.
     pub fn rand<R: Rng>(d: usize, rng: &mut R) -> Self {
@@ -123,6 +165,170 @@ impl<F: Field> DensePolynomial<F> {
         &self.coeffs
     }
 
+    /// Multiply `self` by `x^n`.
+    pub fn mul_by_xn(&self, n: usize) -> Self {
+        let mut result = self.clone();
+        result.mul_by_xn_in_place(n);
+        result
+    }
+
+    /// Multiply `self` by `x^n`, by prepending `n` zero coefficients.
+    /// Panics if the resulting degree overflows `usize`.
+    pub fn mul_by_xn_in_place(&mut self, n: usize) {
+        if !self.is_zero() {
+            self.coeffs.len().checked_add(n).expect("Degree overflow when multiplying by x^n");
+            self.coeffs.splice(0..0, vec![F::zero(); n]);
+        }
+    }
+
+    /// Returns `Some(k)` if `self == other * x^k`, where `k` may be negative, and `None` otherwise.
+    /// Trailing zero coefficients are ignored. Two zero polynomials are related by `k = 0`.
+    pub fn shift_relation(&self, other: &DensePolynomial<F>) -> Option<isize> {
+        // Returns the number of low-order zero coefficients of `coeffs`, and the nonzero coefficients after them.
+        fn strip<F: Field>(coeffs: &[F]) -> Option<(usize, &[F])> {
+            let start = coeffs.iter().position(|c| !c.is_zero())?;
+            let end = coeffs.iter().rposition(|c| !c.is_zero())?;
+            Some((start, &coeffs[start..=end]))
+        }
+        match (strip(&self.coeffs), strip(&other.coeffs)) {
+            (None, None) => Some(0),
+            (Some((a, lhs)), Some((b, rhs))) if lhs == rhs => Some(a as isize - b as isize),
+            _ => None,
+        }
+    }
+
+    /// Split `self` into chunks of degree at most `max_degree`, for committing with a key that only
+    /// supports polynomials of degree at most `max_degree`.
+    ///
+    /// The `k`-th chunk holds the coefficients of `x^{k * (max_degree + 1)}` through
+    /// `x^{(k + 1) * (max_degree + 1) - 1}`.
+    /// If `rebase` is true, each chunk is shifted down to start at degree 0, so that `self` is the sum of
+    /// `x^{k * (max_degree + 1)} * chunks[k]`. Otherwise, each chunk keeps its original degrees, so that `self` is
+    /// the sum of the chunks (and only the rebased chunks are within `max_degree`).
+    /// Chunks in which all coefficients are zero are kept as zero polynomials, so that the index of a chunk
+    /// always determines its offset. The zero polynomial has no chunks.
+    pub fn chunk_for_degree(&self, max_degree: usize, rebase: bool) -> Vec<DensePolynomial<F>> {
+        let chunk_size = max_degree.saturating_add(1);
+        self.coeffs
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(k, chunk)| {
+                let chunk = Self::from_coefficients_slice(chunk);
+                if rebase { chunk } else { chunk.mul_by_xn(k * chunk_size) }
+            })
+            .collect()
+    }
+
+    /// Add `scalar * other` to `self`, first padding `self` with zeros if `other` has more coefficients,
+    /// and then removing any resulting trailing zeros, as `+=` does.
+    pub fn add_scaled(&mut self, scalar: F, other: &Self) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), F::zero());
+        }
+        // Zip safety: `other` has at most as many coefficients as `self` after the resize.
+        cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a += scalar * b);
+        while let Some(true) = self.coeffs.last().map(|c| c.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Resize the coefficient vector of `self` to hold exactly the coefficients of degree at most `degree`.
+    ///
+    /// Growing pads `self` with zeros, so that the coefficients up to `degree` can be written in place
+    /// without reallocating. The padded `self` is a non-canonical buffer rather than a polynomial: until its
+    /// top coefficient is set to a nonzero value, it has trailing zeros, so methods that assume there are none,
+    /// such as [`Self::degree`], must not be called on it. Operations that remove trailing zeros, such as
+    /// [`Self::add_scaled`] or [`Self::make_monic`], restore the canonical form.
+    ///
+    /// Shrinking drops the coefficients above `degree`, and then removes any resulting trailing zeros,
+    /// so the stored length may end up below `degree + 1`.
+    ///
+    /// Returns an error, leaving `self` unchanged, if `degree + 1` overflows `usize`.
+    pub fn resize_to_degree(&mut self, degree: usize) -> Result<(), PolynomialError> {
+        let len = degree.checked_add(1).ok_or(PolynomialError::DegreeOverflow { degree })?;
+        if len >= self.coeffs.len() {
+            self.coeffs.resize(len, F::zero());
+        } else {
+            self.coeffs.truncate(len);
+            while let Some(true) = self.coeffs.last().map(|c| c.is_zero()) {
+                self.coeffs.pop();
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the inverse of the leading coefficient of `self`, ignoring any trailing zero coefficients,
+    /// or `None` if `self` is the zero polynomial.
+    pub fn leading_coeff_inverse(&self) -> Option<F> {
+        self.coeffs.iter().rev().find(|c| !c.is_zero()).and_then(|c| c.inverse())
+    }
+
+    /// Checks if the leading coefficient of `self` is `1`, ignoring any trailing zero coefficients.
+    /// The zero polynomial is not monic.
+    pub fn is_monic(&self) -> bool {
+        matches!(self.coeffs.iter().rev().find(|c| !c.is_zero()), Some(c) if c.is_one())
+    }
+
+    /// Divide `self` by its leading coefficient, so that `self` becomes monic, using a single inversion.
+    /// Any trailing zero coefficients are removed first.
+    ///
+    /// Returns the previous leading coefficient, or `None` (leaving `self` unchanged) if `self` is the zero polynomial.
+    pub fn make_monic(&mut self) -> Option<F> {
+        let degree = self.coeffs.iter().rposition(|c| !c.is_zero())?;
+        self.coeffs.truncate(degree + 1);
+        let leading_coeff = self.coeffs[degree];
+        if !leading_coeff.is_one() {
+            // The leading coefficient is nonzero, so it has an inverse.
+            let inverse = leading_coeff.inverse().unwrap();
+            cfg_iter_mut!(self.coeffs[..degree]).for_each(|c| *c *= inverse);
+            self.coeffs[degree] = F::one();
+        }
+        Some(leading_coeff)
+    }
+
+    /// Returns the coefficient of `x^degree` in `self`, which is zero if `degree` exceeds the degree of `self`.
+    pub fn coefficient(&self, degree: usize) -> F {
+        self.coeffs.get(degree).copied().unwrap_or_else(F::zero)
+    }
+
+    /// Set the coefficient of `x^degree` in `self` to `value`, growing the coefficient vector if needed,
+    /// and removing any resulting trailing zeros.
+    pub fn set_coefficient(&mut self, degree: usize, value: F) {
+        if degree >= self.coeffs.len() {
+            if value.is_zero() {
+                return;
+            }
+            self.coeffs.resize(degree + 1, F::zero());
+        }
+        self.coeffs[degree] = value;
+        while let Some(true) = self.coeffs.last().map(|c| c.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Overwrite every coefficient of `self`, including the spare capacity of the coefficient vector,
+    /// with zeros, leaving `self` as the zero polynomial without releasing its allocation.
+    pub fn secure_clear(&mut self) {
+        self.coeffs.resize(self.coeffs.capacity(), F::zero());
+        self.coeffs.iter_mut().for_each(|c| *c = F::zero());
+        // Prevent the compiler from eliding the writes above as dead stores.
+        std::hint::black_box(&mut self.coeffs);
+        self.coeffs.clear();
+    }
+
+    /// Map each coefficient of `self` into the field `G`.
+    /// The result is only meaningful if the conversion from `F` to `G` is a ring homomorphism.
+    pub fn convert_field<G: Field + From<F>>(&self) -> DensePolynomial<G> {
+        DensePolynomial::from_coefficients_vec(self.coeffs.iter().map(|c| G::from(*c)).collect())
+    }
+
+    /// Map each coefficient of `self` into the field `G`, failing if any coefficient cannot be converted.
+    /// The result is only meaningful if the conversion from `F` to `G` is a ring homomorphism.
+    pub fn try_convert_field<G: Field + TryFrom<F>>(&self) -> Result<DensePolynomial<G>, G::Error> {
+        let coeffs = self.coeffs.iter().map(|c| G::try_from(*c)).collect::<Result<Vec<_>, _>>()?;
+        Ok(DensePolynomial::from_coefficients_vec(coeffs))
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
     #[cfg(test)]
     fn naive_mul(&self, other: &Self) -> Self {
@@ -141,8 +347,52 @@ impl<F: Field> DensePolynomial<F> {
 }
 
 impl<F: PrimeField> DensePolynomial<F> {
+    /// Compute the composition `self(inner(x))` via Horner's method.
+    ///
+    /// The result has degree `self.degree() * inner.degree()`, so composing polynomials of large degree
+    /// can be very expensive. Returns an error, without computing anything, if that degree exceeds `max_degree`.
+    pub fn compose(
+        &self,
+        inner: &DensePolynomial<F>,
+        max_degree: usize,
+    ) -> Result<DensePolynomial<F>, PolynomialError> {
+        let (outer, inner_degree) = (self.degree(), inner.degree());
+        match outer.checked_mul(inner_degree) {
+            Some(degree) if degree <= max_degree => {}
+            _ => {
+                return Err(PolynomialError::CompositionDegreeExceedsBound {
+                    outer,
+                    inner: inner_degree,
+                    bound: max_degree,
+                });
+            }
+        }
+        let mut result = DensePolynomial::zero();
+        for coeff in self.coeffs.iter().rev() {
+            result = &result * inner;
+            if result.coeffs.is_empty() {
+                result.coeffs.push(*coeff);
+            } else {
+                result.coeffs[0] += coeff;
+            }
+        }
+        Ok(DensePolynomial::from_coefficients_vec(result.coeffs))
+    }
+
+    /// Returns the coefficients of `self(factor * x)`, by multiplying the coefficient of `x^i` by `factor^i`.
+    /// With `factor = domain.group_gen^k`, this is the coefficient-form counterpart of [`Evaluations::rotate`] by `k`.
+    pub fn shift_argument_by(&self, factor: F) -> DensePolynomial<F> {
+        let mut coeffs = self.coeffs.clone();
+        EvaluationDomain::distribute_powers(&mut coeffs, factor);
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
     /// Multiply `self` by the vanishing polynomial for the domain `domain`.
     pub fn mul_by_vanishing_poly(&self, domain: EvaluationDomain<F>) -> DensePolynomial<F> {
+        domain
+            .size()
+            .checked_add(self.coeffs.len())
+            .expect("Degree overflow when multiplying by the vanishing polynomial");
         let mut shifted = vec![F::zero(); domain.size()];
         shifted.extend_from_slice(&self.coeffs);
         crate::cfg_iter_mut!(shifted[..self.coeffs.len()]).zip_eq(&self.coeffs).for_each(|(s, c)| *s -= c);
@@ -171,11 +421,203 @@ impl<F: PrimeField> DensePolynomial<F> {
         let poly: Polynomial<'_, F> = self.into();
         Polynomial::<F>::evaluate_over_domain(poly, domain)
     }
+
+    /// Compute the product of all polynomials in `polys`.
+    ///
+    /// Every polynomial is transformed once over a single domain of size at least
+    /// `sum_i deg(polys[i]) + 1`, which is exactly large enough to hold the product without wraparound.
+    /// The evaluations are multiplied pointwise in a (parallel) reduction tree, and the result is
+    /// interpolated once. This avoids recomputing FFTs of intermediate products, as happens when
+    /// multiplying the polynomials pairwise.
+    ///
+    /// The product of an empty list is the constant polynomial `1`.
+    /// Panics if `F` does not have a subgroup that is large enough for the product.
+    pub fn product_of(polys: &[DensePolynomial<F>]) -> DensePolynomial<F> {
+        if polys.iter().any(|p| p.is_zero()) {
+            return DensePolynomial::zero();
+        }
+        match polys {
+            [] => DensePolynomial::from_coefficients_vec(vec![F::one()]),
+            [p] => p.clone(),
+            _ => {
+                let num_coeffs = polys
+                    .iter()
+                    .try_fold(1usize, |acc, p| acc.checked_add(p.degree()))
+                    .expect("Degree overflow when multiplying polynomials");
+                let domain = EvaluationDomain::<F>::new(num_coeffs).expect("The field is too small for the product");
+                let evaluations = cfg_iter!(polys).map(|p| domain.fft(&p.coeffs)).collect::<Vec<_>>();
+                let mut product = cfg_reduce_with!(cfg_into_iter!(evaluations), |mut a, b| {
+                    cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
+                    a
+                })
+                .unwrap();
+                domain.ifft_in_place(&mut product);
+                DensePolynomial::from_coefficients_vec(product)
+            }
+        }
+    }
+
+    /// Evaluate `self` over `domain`, either by Horner's method at each element of `domain`,
+    /// or by an FFT, whichever is cheaper according to [`HORNER_EVALUATION_CROSSOVER`].
+    /// Horner's method takes `O(domain.size() * self.degree())` time, while the FFT takes
+    /// `O(domain.size() * log(domain.size()))` time, so the former only wins for small degrees.
+    pub fn evaluate_at_domain_via_fft_or_horner(&self, domain: EvaluationDomain<F>) -> Evaluations<F> {
+        let log_size = core::cmp::max(domain.log_size_of_group as usize, 1);
+        if self.coeffs.len() <= HORNER_EVALUATION_CROSSOVER * log_size {
+            let elements = domain.elements().collect::<Vec<_>>();
+            let evaluations = cfg_iter!(elements).map(|e| self.evaluate(*e)).collect();
+            Evaluations::from_vec_and_domain(evaluations, domain)
+        } else {
+            self.evaluate_over_domain_by_ref(domain)
+        }
+    }
+
+    /// Evaluate `self` over the coset `offset * domain`.
+    /// The evaluations are tagged with `domain`, and `self` can be recovered from them via
+    /// `domain.get_coset(offset).unwrap().ifft(&evaluations.evaluations)`.
+    ///
+    /// Panics if `self` has more coefficients than `domain` has elements, or if `offset` is zero.
+    pub fn evaluate_over_coset(&self, domain: EvaluationDomain<F>, offset: F) -> Evaluations<F> {
+        assert!(self.coeffs.len() <= domain.size(), "The polynomial is too large for the domain");
+        let coset = domain.get_coset(offset).expect("The coset offset must be nonzero");
+        Evaluations::from_vec_and_domain(coset.fft(&self.coeffs), domain)
+    }
+
+    /// Evaluate `self` over the coset `offset * domain` as in [`Self::evaluate_over_coset`], but without
+    /// allocating a second buffer: `self` is consumed, and its coefficients are scaled by the powers of `offset`
+    /// and transformed in place, so the returned evaluations reuse the memory of the coefficients.
+    /// The buffer is only reallocated if its capacity is smaller than the size of `domain`.
+    ///
+    /// Panics if `self` has more coefficients than `domain` has elements, or if `offset` is zero.
+    pub fn evaluate_over_domain_coset_in_place(self, domain: EvaluationDomain<F>, offset: F) -> Evaluations<F> {
+        assert!(self.coeffs.len() <= domain.size(), "The polynomial is too large for the domain");
+        let coset = domain.get_coset(offset).expect("The coset offset must be nonzero");
+        let mut coeffs = self.coeffs;
+        coset.fft_in_place(&mut coeffs);
+        Evaluations::from_vec_and_domain(coeffs, domain)
+    }
+
+    /// Interpolate the unique polynomial of degree less than `points.len()` that passes through every
+    /// `(x, y)` in `points`, where the `x`-coordinates are arbitrary rather than the elements of a subgroup.
+    ///
+    /// This evaluates the barycentric weights `1 / Z'(x_i)`, where `Z(X) = prod_i (X - x_i)`, and combines
+    /// the weighted `y_i` using a subproduct tree of the linear factors `X - x_i`. With FFT-based multiplication
+    /// and Newton-iteration division, this takes `O(n log^2 n)` time for `n` points.
+    /// For points forming an [`EvaluationDomain`], [`Evaluations::interpolate`] is faster.
+    ///
+    /// Panics if two points have the same `x`-coordinate, as then `Z'(x_i) = 0` and no weight exists.
+    pub fn interpolate_points(points: &[(F, F)]) -> DensePolynomial<F> {
+        if points.is_empty() {
+            return DensePolynomial::zero();
+        }
+        let tree = Self::subproduct_tree(points.iter().map(|(x, _)| *x));
+        let vanishing_poly = &tree[tree.len() - 1][0];
+        let derivative = DensePolynomial::from_coefficients_vec(
+            vanishing_poly.coeffs.iter().enumerate().skip(1).map(|(i, c)| F::from(i as u64) * c).collect(),
+        );
+        let weights = Self::evaluate_over_subproduct_tree(&derivative, &tree, points.iter().map(|(x, _)| *x));
+
+        // Combine the weighted values up the tree: a node with children `(l, r)` holds
+        // `poly_l * Z_r + poly_r * Z_l`, where `Z_l` and `Z_r` are the children's subproducts.
+        let mut level = weights
+            .into_iter()
+            .zip(points)
+            .map(|(weight, (_, y))| {
+                let weight_inv = weight.inverse().expect("Cannot interpolate points with duplicate x-coordinates");
+                DensePolynomial::from_coefficients_vec(vec![*y * weight_inv])
+            })
+            .collect::<Vec<_>>();
+        for products in &tree[..tree.len() - 1] {
+            level = level
+                .chunks(2)
+                .zip(products.chunks(2))
+                .map(|(polys, factors)| match (polys, factors) {
+                    ([left, right], [left_factor, right_factor]) => &(left * right_factor) + &(right * left_factor),
+                    _ => polys[0].clone(),
+                })
+                .collect();
+        }
+        level.pop().unwrap()
+    }
+
+    /// Returns the subproduct tree of the linear factors `X - x_i`, from the leaves up to the root.
+    /// Each level holds the products of adjacent pairs from the level below, and an unpaired last
+    /// element is carried up unchanged, so the final level holds the single product `prod_i (X - x_i)`.
+    fn subproduct_tree(xs: impl Iterator<Item = F>) -> Vec<Vec<DensePolynomial<F>>> {
+        let leaves = xs.map(|x| DensePolynomial::from_coefficients_vec(vec![-x, F::one()])).collect::<Vec<_>>();
+        let mut tree = vec![leaves];
+        while tree[tree.len() - 1].len() > 1 {
+            let pairs = tree[tree.len() - 1].chunks(2).collect::<Vec<_>>();
+            let level = cfg_iter!(pairs)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            tree.push(level);
+        }
+        tree
+    }
+
+    /// Evaluate `self` at each of the leaves `xs` of the subproduct `tree`,
+    /// by reducing `self` modulo every node on the way down from the root.
+    fn evaluate_over_subproduct_tree(&self, tree: &[Vec<DensePolynomial<F>>], xs: impl Iterator<Item = F>) -> Vec<F> {
+        let mut remainders = vec![self.clone()];
+        for level in tree[1..].iter().rev() {
+            remainders = cfg_iter!(level).enumerate().map(|(i, node)| remainders[i / 2].fast_rem(node)).collect();
+        }
+        // The leaves are linear, so reducing modulo a leaf is just an evaluation at its root.
+        xs.enumerate().map(|(i, x)| remainders[i / 2].evaluate(x)).collect()
+    }
+
+    /// Returns `self mod divisor`, computing the quotient from the inverse of the reversed divisor as a power series.
+    /// Falls back to long division for small divisors, where this has no asymptotic advantage.
+    fn fast_rem(&self, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+        const LONG_DIVISION_THRESHOLD: usize = 64;
+
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return self.clone();
+        }
+        if divisor.degree() < LONG_DIVISION_THRESHOLD {
+            return Polynomial::from(self).rem(&Polynomial::from(divisor));
+        }
+        // Returns the first `n` coefficients of `poly`, padded with zeros.
+        let truncate = |poly: DensePolynomial<F>, n: usize| {
+            let mut coeffs = poly.coeffs;
+            coeffs.resize(n, F::zero());
+            coeffs
+        };
+        let quotient_len = self.degree() - divisor.degree() + 1;
+
+        // Compute `rev(divisor)^{-1} mod X^quotient_len` by Newton iteration, doubling the precision each step.
+        let reversed_divisor = divisor.coeffs.iter().rev().copied().collect::<Vec<_>>();
+        let mut inverse = DensePolynomial::from_coefficients_vec(vec![reversed_divisor[0].inverse().unwrap()]);
+        let mut precision = 1;
+        while precision < quotient_len {
+            precision = core::cmp::min(2 * precision, quotient_len);
+            let reversed_divisor =
+                DensePolynomial::from_coefficients_slice(&reversed_divisor[..core::cmp::min(precision, divisor.len())]);
+            // `2 - rev(divisor) * inverse`
+            let mut error = truncate(-(&reversed_divisor * &inverse), precision);
+            error[0] += F::one().double();
+            let product = &inverse * &DensePolynomial::from_coefficients_vec(error);
+            inverse = DensePolynomial::from_coefficients_vec(truncate(product, precision));
+        }
+
+        // `rev(quotient) = rev(self) * rev(divisor)^{-1} mod X^quotient_len`.
+        let reversed_self = self.coeffs.iter().rev().take(quotient_len).copied().collect::<Vec<_>>();
+        let product = &DensePolynomial::from_coefficients_vec(reversed_self) * &inverse;
+        let mut quotient = truncate(product, quotient_len);
+        quotient.reverse();
+        let quotient = DensePolynomial::from_coefficients_vec(quotient);
+        // The leading coefficients cancel, so trim them from the remainder.
+        DensePolynomial::from_coefficients_vec((self - &(&quotient * divisor)).coeffs)
+    }
 }
 
 impl<F: Field> From<super::SparsePolynomial<F>> for DensePolynomial<F> {
     fn from(other: super::SparsePolynomial<F>) -> Self {
-        let mut result = vec![F::zero(); other.degree() + 1];
+        let mut result = vec![F::zero(); other.degree().checked_add(1).expect("Degree overflow when densifying")];
         for (i, coeff) in other.coeffs() {
             result[*i] = *coeff;
         }
@@ -259,22 +701,8 @@ impl<'a, F: Field> AddAssign<(F, &'a DensePolynomial<F>)> for DensePolynomial<F>
             self.coeffs.iter_mut().for_each(|c| *c *= &f);
         } else if other.is_zero() {
             // return
-        } else if self.degree() >= other.degree() {
-            // Zip safety: `self` and `other` could have different lengths.
-            cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| {
-                *a += f * b;
-            });
         } else {
-            // Add the necessary number of zero coefficients.
-            self.coeffs.resize(other.coeffs.len(), F::zero());
-            // Zip safety: `self` and `other` have the same length after the resize.
-            cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| {
-                *a += f * b;
-            });
-            // If the leading coefficient ends up being zero, pop it off.
-            while self.coeffs.last().unwrap().is_zero() {
-                self.coeffs.pop();
-            }
+            self.add_scaled(f, other);
         }
     }
 }
@@ -567,6 +995,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn evaluate_at_domain_via_fft_or_horner() {
+        let rng = &mut TestRng::default();
+        for log_size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let crossover = HORNER_EVALUATION_CROSSOVER * core::cmp::max(log_size, 1);
+            // Cover the Horner branch, the FFT branch, and polynomials larger than the domain.
+            for num_coeffs in [1, crossover, crossover + 1, 2 * domain.size() + 3] {
+                let p = DensePolynomial::<Fr>::rand(num_coeffs - 1, rng);
+                let expected = domain.elements().map(|e| p.evaluate(e)).collect::<Vec<_>>();
+                assert_eq!(p.evaluate_at_domain_via_fft_or_horner(domain).evaluations, expected);
+                assert_eq!(p.evaluate_over_domain_by_ref(domain).evaluations, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn product_of() {
+        let rng = &mut TestRng::default();
+        assert_eq!(DensePolynomial::<Fr>::product_of(&[]), DensePolynomial::from_coefficients_vec(vec![Fr::one()]));
+
+        for num_polys in 1..10 {
+            let polys = (0..num_polys).map(|i| DensePolynomial::<Fr>::rand(3 * i % 7, rng)).collect::<Vec<_>>();
+            let expected =
+                polys.iter().fold(DensePolynomial::from_coefficients_vec(vec![Fr::one()]), |acc, p| &acc * p);
+            let product = DensePolynomial::product_of(&polys);
+            assert_eq!(product, expected);
+            assert_eq!(product.degree(), polys.iter().map(|p| p.degree()).sum::<usize>());
+
+            // A zero factor makes the product zero.
+            let mut polys = polys;
+            polys.push(DensePolynomial::zero());
+            assert!(DensePolynomial::product_of(&polys).is_zero());
+        }
+
+        // Factors whose degrees sum to exactly one less than a power of two fill the domain without wrapping around.
+        let polys = (0..3).map(|_| DensePolynomial::<Fr>::rand(5, rng)).collect::<Vec<_>>();
+        let num_coeffs = polys.iter().map(|p| p.degree()).sum::<usize>() + 1;
+        assert_eq!(EvaluationDomain::<Fr>::new(num_coeffs).unwrap().size(), 16);
+        let product = DensePolynomial::product_of(&polys);
+        assert_eq!(product.degree(), 15);
+        assert_eq!(product, &(&polys[0] * &polys[1]) * &polys[2]);
+        let point = Fr::rand(rng);
+        assert_eq!(product.evaluate(point), polys.iter().map(|p| p.evaluate(point)).product());
+    }
+
+    #[test]
+    fn rem_matches_division() {
+        let rng = &mut TestRng::default();
+
+        for a_degree in 0..40 {
+            for b_degree in 0..40 {
+                let dividend = Polynomial::from(DensePolynomial::<Fr>::rand(a_degree, rng));
+                let divisor = Polynomial::from(DensePolynomial::<Fr>::rand(b_degree, rng));
+                let (_, remainder) = dividend.divide_with_q_and_r(&divisor).unwrap();
+                assert_eq!(dividend.rem(&divisor), remainder);
+            }
+        }
+
+        // The special case `x^n - 1`, as a sparse or a dense modulus.
+        for n in 1..20 {
+            let sparse = SparsePolynomial::from_coefficients([(0, -Fr::one()), (n, Fr::one())]);
+            let dense = DensePolynomial::from(sparse.clone());
+            for a_degree in [0, n - 1, n, 3 * n + 1] {
+                let dividend = Polynomial::from(DensePolynomial::<Fr>::rand(a_degree, rng));
+                let (_, remainder) = dividend.divide_with_q_and_r(&Polynomial::from(&sparse)).unwrap();
+                assert_eq!(dividend.rem(&Polynomial::from(&sparse)), remainder);
+                assert_eq!(dividend.rem(&Polynomial::from(&dense)), remainder);
+            }
+        }
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut TestRng::default();
@@ -581,6 +1081,262 @@ mod tests {
         }
     }
 
+    #[test]
+    fn iter_nonzero_terms() {
+        let (zero, one, two) = (Fr::zero(), Fr::one(), Fr::one().double());
+        let dense = DensePolynomial { coeffs: vec![zero, one, zero, two, zero] };
+        let sparse = SparsePolynomial::from_coefficients([(3, two), (1, one)]);
+        let expected = vec![(1, one), (3, two)];
+        assert_eq!(Polynomial::from(&dense).iter_nonzero_terms().collect::<Vec<_>>(), expected);
+        assert_eq!(Polynomial::from(sparse).iter_nonzero_terms().collect::<Vec<_>>(), expected);
+        assert_eq!(Polynomial::<Fr>::zero().iter_nonzero_terms().count(), 0);
+    }
+
+    #[test]
+    fn from_coefficients() {
+        let rng = &mut TestRng::default();
+        let mut coeffs = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        coeffs.extend([Fr::zero(); 5]);
+
+        let expected = DensePolynomial::from_coefficients_vec(coeffs.clone());
+        assert_eq!(expected.coeffs.len(), 10);
+        let from_slice = DensePolynomial::from_coefficients_slice(&coeffs);
+        assert_eq!(from_slice, expected);
+        assert_eq!(from_slice.coeffs.capacity(), 10);
+        assert_eq!(DensePolynomial::from_coefficients_iter(coeffs.iter().copied()), expected);
+
+        assert!(DensePolynomial::<Fr>::from_coefficients_slice(&[Fr::zero(); 3]).coeffs.is_empty());
+        assert!(DensePolynomial::<Fr>::from_coefficients_iter(std::iter::empty()).is_zero());
+    }
+
+    #[test]
+    fn shift_relation() {
+        let rng = &mut TestRng::default();
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+        assert_eq!(p.shift_relation(&p), Some(0));
+        assert_eq!(p.mul_by_xn(3).shift_relation(&p), Some(3));
+        assert_eq!(p.shift_relation(&p.mul_by_xn(3)), Some(-3));
+        assert_eq!(p.mul_by_xn(5).shift_relation(&p.mul_by_xn(2)), Some(3));
+
+        // Trailing zeros are ignored.
+        let mut padded = p.mul_by_xn(1);
+        padded.coeffs.extend([Fr::zero(); 4]);
+        assert_eq!(padded.shift_relation(&p), Some(1));
+
+        // Zero polynomials.
+        let zero = DensePolynomial::<Fr>::zero();
+        assert_eq!(zero.shift_relation(&zero), Some(0));
+        assert_eq!(DensePolynomial { coeffs: vec![Fr::zero(); 3] }.shift_relation(&zero), Some(0));
+        assert_eq!(zero.shift_relation(&p), None);
+        assert_eq!(p.shift_relation(&zero), None);
+
+        // Unrelated polynomials, including scalar multiples.
+        assert_eq!(p.shift_relation(&DensePolynomial::rand(10, rng)), None);
+        assert_eq!(p.shift_relation(&(&p * Fr::from(2u8))), None);
+        assert_eq!(p.shift_relation(&DensePolynomial::from_coefficients_slice(&p.coeffs[1..])), None);
+    }
+
+    #[test]
+    fn chunk_for_degree() {
+        let rng = &mut TestRng::default();
+        let point = Fr::rand(rng);
+        for (degree, max_degree) in [(0, 0), (9, 0), (9, 4), (10, 4), (11, 4), (3, 10), (31, 7)] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+
+            let chunks = p.chunk_for_degree(max_degree, true);
+            assert_eq!(chunks.len(), degree / (max_degree + 1) + 1);
+            assert!(chunks.iter().all(|chunk| chunk.degree() <= max_degree));
+            let shift = point.pow([(max_degree + 1) as u64]);
+            let evaluation = chunks.iter().rev().fold(Fr::zero(), |acc, chunk| acc * shift + chunk.evaluate(point));
+            assert_eq!(evaluation, p.evaluate(point));
+
+            let chunks = p.chunk_for_degree(max_degree, false);
+            assert_eq!(chunks.iter().fold(DensePolynomial::zero(), |acc, chunk| &acc + chunk), p);
+        }
+
+        // Zero chunks are kept, and the zero polynomial has no chunks.
+        let p = DensePolynomial::from_coefficients_vec(vec![Fr::one(), Fr::zero(), Fr::zero(), Fr::one()]);
+        let chunks = p.chunk_for_degree(0, true);
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks[1].is_zero() && chunks[2].is_zero());
+        assert!(DensePolynomial::<Fr>::zero().chunk_for_degree(4, true).is_empty());
+        assert_eq!(p.chunk_for_degree(usize::MAX, false), vec![p]);
+    }
+
+    #[test]
+    fn resize_to_degree() {
+        let rng = &mut TestRng::default();
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+
+        // Growing pads with zeros, and the padded polynomial can be added to without reallocating.
+        let mut grown = p.clone();
+        grown.resize_to_degree(20).unwrap();
+        assert_eq!(grown.coeffs.len(), 21);
+        assert_eq!(&grown.coeffs[..11], p.coeffs());
+        assert!(grown.coeffs[11..].iter().all(|c| c.is_zero()));
+        let capacity = grown.coeffs.capacity();
+        let q = DensePolynomial::<Fr>::rand(20, rng);
+        grown.add_scaled(Fr::one(), &q);
+        assert_eq!(grown, &p + &q);
+        assert_eq!(grown.coeffs.capacity(), capacity);
+
+        // Shrinking keeps the low coefficients and removes the resulting trailing zeros.
+        let mut shrunk = p.clone();
+        shrunk.resize_to_degree(4).unwrap();
+        assert_eq!(shrunk.coeffs(), &p.coeffs[..5]);
+        let mut padded = p.clone();
+        padded.resize_to_degree(20).unwrap();
+        padded.set_coefficient(3, Fr::zero());
+        padded.resize_to_degree(3).unwrap();
+        assert_eq!(padded.coeffs(), &p.coeffs[..3]);
+
+        // A degree whose number of coefficients overflows is rejected, leaving `self` unchanged.
+        let mut unchanged = p.clone();
+        assert_eq!(unchanged.resize_to_degree(usize::MAX), Err(PolynomialError::DegreeOverflow { degree: usize::MAX }));
+        assert_eq!(unchanged, p);
+    }
+
+    #[test]
+    fn make_monic() {
+        let rng = &mut TestRng::default();
+        for degree in [0, 1, 10, 70] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let leading_coeff = p.coeffs[degree];
+            assert_eq!(p.leading_coeff_inverse(), leading_coeff.inverse());
+            assert!(!p.is_monic());
+
+            // Trailing zeros are ignored and removed.
+            let mut monic = p.clone();
+            monic.resize_to_degree(degree + 5).unwrap();
+            assert_eq!(monic.leading_coeff_inverse(), leading_coeff.inverse());
+            assert_eq!(monic.make_monic(), Some(leading_coeff));
+            assert!(monic.is_monic());
+            assert_eq!(monic.degree(), degree);
+            assert_eq!(&monic * &DensePolynomial::from_coefficients_vec(vec![leading_coeff]), p);
+
+            // A monic polynomial is unchanged.
+            let copy = monic.clone();
+            assert_eq!(monic.make_monic(), Some(Fr::one()));
+            assert_eq!(monic, copy);
+        }
+
+        let mut zero = DensePolynomial::<Fr>::zero();
+        zero.resize_to_degree(3).unwrap();
+        assert_eq!(zero.leading_coeff_inverse(), None);
+        assert!(!zero.is_monic());
+        assert_eq!(zero.make_monic(), None);
+        assert_eq!(zero.coeffs.len(), 4);
+    }
+
+    #[test]
+    fn evaluator_matches_evaluate() {
+        let rng = &mut TestRng::default();
+        for degree in 0..20 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let eval = p.evaluator();
+            for _ in 0..5 {
+                let point = Fr::rand(rng);
+                assert_eq!(eval(point), p.evaluate(point));
+            }
+            assert_eq!(eval(Fr::zero()), p.evaluate(Fr::zero()));
+        }
+        assert_eq!(DensePolynomial::<Fr>::zero().evaluator()(Fr::rand(rng)), Fr::zero());
+    }
+
+    #[test]
+    fn add_scaled() {
+        let rng = &mut TestRng::default();
+        for (a_degree, b_degree) in [(0, 0), (5, 10), (10, 5), (7, 7)] {
+            let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let scalar = Fr::rand(rng);
+
+            let mut result = a.clone();
+            result.add_scaled(scalar, &b);
+            assert_eq!(result, &a + &(&b * scalar));
+            assert_eq!(result.coeffs.len(), a_degree.max(b_degree) + 1);
+        }
+
+        // Trailing zeros are removed, as by `+=`.
+        let a = DensePolynomial::<Fr>::rand(4, rng);
+        let mut result = a.clone();
+        result.add_scaled(-Fr::one(), &a);
+        assert!(result.coeffs.is_empty());
+        let b = DensePolynomial::from_coefficients_vec(vec![Fr::one(), -a.coeffs[4]]).mul_by_xn(3);
+        let mut result = a.clone();
+        result.add_scaled(Fr::one(), &b);
+        assert_eq!(result.degree(), 3);
+        assert_eq!(result.coeffs(), &(&a + &b).coeffs[..4]);
+        let mut zero = DensePolynomial::<Fr>::zero();
+        zero.add_scaled(Fr::rand(rng), &DensePolynomial::zero());
+        assert!(zero.coeffs.is_empty());
+    }
+
+    #[test]
+    fn shift_argument_by() {
+        let rng = &mut TestRng::default();
+        let p = DensePolynomial::<Fr>::rand(20, rng);
+        let (factor, point) = (Fr::rand(rng), Fr::rand(rng));
+        assert_eq!(p.shift_argument_by(factor).evaluate(point), p.evaluate(factor * point));
+        assert_eq!(p.shift_argument_by(Fr::zero()), DensePolynomial::from_coefficients_vec(vec![p.coeffs[0]]));
+
+        // Shifting by a power of the generator matches rotating the evaluations.
+        let domain = EvaluationDomain::<Fr>::new(32).unwrap();
+        let shifted = p.shift_argument_by(domain.group_gen.pow([3]));
+        assert_eq!(shifted.evaluate_over_domain(domain), p.clone().evaluate_over_domain(domain).rotate(3));
+    }
+
+    #[test]
+    fn constant_predicates() {
+        let (zero, one, two) = (Fr::zero(), Fr::one(), Fr::one().double());
+        // (coefficients, is_zero, is_one, is_constant); trailing zeros must not affect the result.
+        let cases = [
+            (vec![], true, false, true),
+            (vec![zero, zero], true, false, true),
+            (vec![one], false, true, true),
+            (vec![one, zero, zero], false, true, true),
+            (vec![two, zero], false, false, true),
+            (vec![one, one], false, false, false),
+            (vec![zero, one, zero], false, false, false),
+        ];
+        for (coeffs, is_zero, is_one, is_constant) in cases {
+            let dense = DensePolynomial { coeffs: coeffs.clone() };
+            let sparse = SparsePolynomial::from_coefficients(coeffs.iter().copied().enumerate());
+            for p in [Polynomial::from(dense.clone()), Polynomial::from(sparse.clone())] {
+                assert_eq!((p.is_zero(), p.is_one(), p.is_constant()), (is_zero, is_one, is_constant), "{coeffs:?}");
+            }
+            assert_eq!((dense.is_zero(), dense.is_one(), dense.is_constant()), (is_zero, is_one, is_constant));
+            assert_eq!((sparse.is_zero(), sparse.is_one(), sparse.is_constant()), (is_zero, is_one, is_constant));
+        }
+    }
+
+    #[test]
+    fn interpolate_points() {
+        let rng = &mut TestRng::default();
+        for num_points in [0, 1, 2, 3, 17, 100, 300] {
+            let poly = DensePolynomial::<Fr>::rand(num_points.max(1) - 1, rng);
+            let points = (0..num_points)
+                .map(|_| {
+                    let x = Fr::rand(rng);
+                    (x, poly.evaluate(x))
+                })
+                .collect::<Vec<_>>();
+            let interpolated = DensePolynomial::interpolate_points(&points);
+            if num_points == 0 {
+                assert!(interpolated.is_zero());
+            } else {
+                assert_eq!(interpolated, poly);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate x-coordinates")]
+    fn interpolate_points_duplicate() {
+        let points = [(Fr::one(), Fr::one()), (Fr::from(2u64), Fr::one()), (Fr::one(), Fr::zero())];
+        let _ = DensePolynomial::interpolate_points(&points);
+    }
+
     #[test]
     fn mul_polynomials_random() {
         let rng = &mut TestRng::default();
@@ -673,4 +1429,158 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn from_coefficients_vec_parallel_matches_sequential() {
+        let rng = &mut TestRng::default();
+        for num_zeros in [0, 1, 10, 5000] {
+            for degree in [0, 1, 70, 5000] {
+                let mut coeffs = DensePolynomial::<Fr>::rand(degree, rng).coeffs;
+                coeffs.extend(vec![Fr::zero(); num_zeros]);
+                assert_eq!(
+                    DensePolynomial::from_coefficients_vec_parallel(coeffs.clone()),
+                    DensePolynomial::from_coefficients_vec(coeffs)
+                );
+            }
+            let zeros = vec![Fr::zero(); num_zeros];
+            assert_eq!(DensePolynomial::from_coefficients_vec_parallel(zeros), DensePolynomial::zero());
+        }
+    }
+
+    #[test]
+    fn compose() {
+        let rng = &mut TestRng::default();
+        for outer_degree in 0..8 {
+            for inner_degree in 0..8 {
+                let p = DensePolynomial::<Fr>::rand(outer_degree, rng);
+                let q = DensePolynomial::<Fr>::rand(inner_degree, rng);
+                let composition = p.compose(&q, outer_degree * inner_degree).unwrap();
+                assert_eq!(composition.degree(), outer_degree * inner_degree);
+                let point = Fr::rand(rng);
+                assert_eq!(composition.evaluate(point), p.evaluate(q.evaluate(point)));
+            }
+        }
+        let p = DensePolynomial::<Fr>::rand(5, rng);
+        let constant = DensePolynomial::from_coefficients_vec(vec![p.coeffs[0]]);
+        assert_eq!(p.compose(&DensePolynomial::zero(), 0), Ok(constant));
+        assert_eq!(DensePolynomial::zero().compose(&p, 0), Ok(DensePolynomial::zero()));
+
+        // Compositions above the degree bound are rejected.
+        let q = DensePolynomial::<Fr>::rand(3, rng);
+        let error = PolynomialError::CompositionDegreeExceedsBound { outer: 5, inner: 3, bound: 14 };
+        assert_eq!(p.compose(&q, 14), Err(error));
+        assert_eq!(p.compose(&q, 15).unwrap().degree(), 15);
+    }
+
+    #[test]
+    fn evaluate_over_coset() {
+        let rng = &mut TestRng::default();
+        for size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << size).unwrap();
+            let offset = Fr::rand(rng);
+            let coset = domain.get_coset(offset).unwrap();
+            for degree in [0, domain.size() / 2, domain.size() - 1] {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                let evals = p.evaluate_over_coset(domain, offset);
+                assert_eq!(evals.domain(), domain);
+                for (element, eval) in coset.elements().zip(&evals.evaluations) {
+                    assert_eq!(p.evaluate(element), *eval);
+                }
+                assert_eq!(DensePolynomial::from_coefficients_vec(coset.ifft(&evals.evaluations)), p);
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_over_domain_coset_in_place() {
+        let rng = &mut TestRng::default();
+        for size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << size).unwrap();
+            let offset = Fr::rand(rng);
+            for degree in [0, domain.size() / 2, domain.size() - 1] {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected = p.evaluate_over_coset(domain, offset);
+
+                let mut coeffs = p.coeffs;
+                coeffs.reserve_exact(domain.size() - coeffs.len());
+                let pointer = coeffs.as_ptr();
+                let evals = DensePolynomial { coeffs }.evaluate_over_domain_coset_in_place(domain, offset);
+                assert_eq!(evals, expected);
+                // The evaluations reuse the buffer of the coefficients.
+                assert_eq!(evals.evaluations.as_ptr(), pointer);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too large for the domain")]
+    fn evaluate_over_domain_coset_in_place_too_large() {
+        let rng = &mut TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(4).unwrap();
+        let _ = DensePolynomial::<Fr>::rand(4, rng).evaluate_over_domain_coset_in_place(domain, Fr::rand(rng));
+    }
+
+    #[test]
+    fn add_chooses_representation() {
+        let rng = &mut TestRng::default();
+        let dense = DensePolynomial::<Fr>::rand(20, rng);
+        let small_dense = DensePolynomial::<Fr>::rand(2, rng);
+        let sparse = SparsePolynomial::<Fr>::rand(3, 100, rng);
+        let other_sparse = SparsePolynomial::<Fr>::rand(70, 100, rng);
+        let point = Fr::rand(rng);
+
+        let polys = [
+            Polynomial::from(&dense),
+            Polynomial::from(&small_dense),
+            Polynomial::from(&sparse),
+            Polynomial::from(&other_sparse),
+        ];
+        for a in &polys {
+            for b in &polys {
+                let sum = a.add(b);
+                assert_eq!(sum.evaluate(point), a.evaluate(point) + b.evaluate(point));
+                let num_terms = sum.coeffs().filter(|(_, c)| !c.is_zero()).count();
+                assert_eq!(sum.as_dense().is_none(), Polynomial::<Fr>::prefers_sparse(num_terms, sum.degree()));
+            }
+        }
+        // A few terms of high degree stay sparse, even when added to a small dense polynomial.
+        assert!(polys[1].add(&polys[2]).as_dense().is_none());
+        // Many terms become dense.
+        assert!(polys[2].add(&polys[3]).as_dense().is_some());
+    }
+
+    #[test]
+    fn mul_by_xn() {
+        let rng = &mut TestRng::default();
+        for degree in 0..20 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            for n in 0..20 {
+                let mut xn = vec![Fr::zero(); n];
+                xn.push(Fr::one());
+                let expected = &p * &DensePolynomial::from_coefficients_vec(xn);
+                assert_eq!(p.mul_by_xn(n), expected);
+
+                let sparse = SparsePolynomial::from_coefficients(p.coeffs.iter().copied().enumerate());
+                assert_eq!(DensePolynomial::from(sparse.mul_by_xn(n)), expected);
+            }
+        }
+        assert_eq!(DensePolynomial::<Fr>::zero().mul_by_xn(5), DensePolynomial::zero());
+    }
+
+    #[test]
+    fn leading_and_constant_coefficients() {
+        let rng = &mut TestRng::default();
+        for degree in 0..70 {
+            let dense = DensePolynomial::<Fr>::rand(degree, rng);
+            let sparse = SparsePolynomial::from_coefficients(dense.coeffs.iter().copied().enumerate());
+            let dense = Polynomial::from(dense);
+            let sparse = Polynomial::from(sparse);
+            assert_eq!(dense.leading_coefficient(), sparse.leading_coefficient());
+            assert_eq!(dense.constant_term(), sparse.constant_term());
+        }
+        let sparse = Polynomial::from(SparsePolynomial::from_coefficients_slice(&[(3, Fr::one())]));
+        assert_eq!(sparse.leading_coefficient(), Some(Fr::one()));
+        assert_eq!(sparse.constant_term(), Fr::zero());
+        assert_eq!(Polynomial::<Fr>::zero().leading_coefficient(), None);
+    }
 }
@@ -15,8 +15,8 @@
 //! Work with sparse and dense polynomials.
 
 use crate::fft::{EvaluationDomain, Evaluations};
-use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*, SerializationError};
+use snarkvm_fields::{FftField, Field, PrimeField};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*, SerializationError};
 
 use std::{borrow::Cow, convert::TryInto};
 
@@ -25,17 +25,42 @@ use Polynomial::*;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+mod bivariate;
+pub use bivariate::BivariatePolynomial;
+
 mod dense;
-pub use dense::DensePolynomial;
+pub use dense::{DensePolynomial, HORNER_EVALUATION_CROSSOVER};
 
 mod sparse;
 pub use sparse::SparsePolynomial;
 
+mod multilinear;
+pub use multilinear::MultilinearPolynomial;
+
 mod multiplier;
 pub use multiplier::*;
 
+/// An error that occurs when a polynomial operation would produce a polynomial of too large a degree.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum PolynomialError {
+    /// The coefficients of a polynomial of the requested degree do not fit in a `Vec`.
+    #[error("A polynomial of degree {degree} has more coefficients than fit in a usize")]
+    DegreeOverflow { degree: usize },
+    /// Composing polynomials of the given degrees would produce a degree above the caller's bound.
+    #[error("Composing polynomials of degrees {outer} and {inner} exceeds the degree bound {bound}")]
+    CompositionDegreeExceedsBound { outer: usize, inner: usize, bound: usize },
+}
+
 /// Represents either a sparse polynomial or a dense one.
+/// With the `serde` feature, it is serialized as a map from `Sparse` or `Dense` to the polynomial,
+/// so that deserialization reconstructs the same representation. As in the canonical format,
+/// deserialization rejects polynomials that do not pass [`Valid::check`].
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(serialize = "", deserialize = "F: FftField"), try_from = "UncheckedPolynomial<F>")
+)]
 pub enum Polynomial<'a, F: Field> {
     /// Represents the case where `self` is a sparse polynomial
     Sparse(Cow<'a, SparsePolynomial<F>>),
@@ -43,6 +68,33 @@ pub enum Polynomial<'a, F: Field> {
     Dense(Cow<'a, DensePolynomial<F>>),
 }
 
+/// A polynomial as deserialized by `serde`, before it is validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "")]
+enum UncheckedPolynomial<F: FftField> {
+    Sparse(SparsePolynomial<F>),
+    Dense(DensePolynomial<F>),
+}
+
+#[cfg(feature = "serde")]
+impl<'a, F: FftField> TryFrom<UncheckedPolynomial<F>> for Polynomial<'a, F> {
+    type Error = SerializationError;
+
+    fn try_from(polynomial: UncheckedPolynomial<F>) -> Result<Self, Self::Error> {
+        let polynomial = match polynomial {
+            UncheckedPolynomial::Sparse(p) => Sparse(Cow::Owned(p)),
+            UncheckedPolynomial::Dense(p) => Dense(Cow::Owned(p)),
+        };
+        polynomial.check()?;
+        Ok(polynomial)
+    }
+}
+
+/// Polynomials are serialized as the coefficients of their dense form, forwarding the `Compress` mode
+/// to the coefficients. Field elements have the same encoding in both modes, so the mode only affects
+/// the serialization of types that contain curve points alongside polynomials.
+/// As a consequence, a sparse polynomial is deserialized as an equal dense polynomial.
 impl<'a, F: Field> CanonicalSerialize for Polynomial<'a, F> {
     #[allow(unused_mut, unused_variables)]
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
@@ -67,20 +119,33 @@ impl<'a, F: Field> CanonicalSerialize for Polynomial<'a, F> {
     }
 }
 
-impl<'a, F: Field> Valid for Polynomial<'a, F> {
+impl<'a, F: FftField> Valid for Polynomial<'a, F> {
+    /// Check that `self` is in canonical form, so that it is safe to use, e.g. to compute its degree:
+    /// a dense polynomial must not have trailing zeros, and a sparse one must pass [`SparsePolynomial::check`].
     fn check(&self) -> Result<(), SerializationError> {
-        Ok(())
+        match self {
+            Sparse(p) => p.check(),
+            Dense(p) => match p.coeffs.last() {
+                Some(c) if c.is_zero() => Err(SerializationError::InvalidData),
+                _ => p.check(),
+            },
+        }
     }
 }
 
-impl<'a, F: Field> CanonicalDeserialize for Polynomial<'a, F> {
+impl<'a, F: FftField> CanonicalDeserialize for Polynomial<'a, F> {
     #[allow(unused_mut, unused_variables)]
     fn deserialize_with_mode<R: Read>(
         reader: R,
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError> {
-        DensePolynomial::<F>::deserialize_with_mode(reader, compress, validate).map(|e| Self::Dense(Cow::Owned(e)))
+        let polynomial =
+            Self::Dense(Cow::Owned(DensePolynomial::<F>::deserialize_with_mode(reader, compress, validate)?));
+        if let Validate::Yes = validate {
+            polynomial.check()?;
+        }
+        Ok(polynomial)
     }
 }
 
@@ -143,6 +208,57 @@ impl<'a, F: Field> Polynomial<'a, F> {
         }
     }
 
+    /// Checks if the given polynomial is the constant `1`.
+    pub fn is_one(&self) -> bool {
+        match self {
+            Sparse(s) => s.is_one(),
+            Dense(d) => d.is_one(),
+        }
+    }
+
+    /// Checks if the given polynomial is constant (including zero).
+    pub fn is_constant(&self) -> bool {
+        match self {
+            Sparse(s) => s.is_constant(),
+            Dense(d) => d.is_constant(),
+        }
+    }
+
+    /// Returns the coefficient of `x^degree` in `self`.
+    pub fn coefficient(&self, degree: usize) -> F {
+        match self {
+            Sparse(p) => p.coefficient(degree),
+            Dense(p) => p.coefficient(degree),
+        }
+    }
+
+    /// Set the coefficient of `x^degree` in `self` to `value`, keeping `self` in canonical form.
+    pub fn set_coefficient(&mut self, degree: usize, value: F) {
+        match self {
+            Sparse(p) => p.to_mut().set_coefficient(degree, value),
+            Dense(p) => p.to_mut().set_coefficient(degree, value),
+        }
+    }
+
+    /// Overwrite the coefficients of `self` with zeros, leaving `self` as the zero polynomial.
+    /// Borrowed polynomials are not owned by `self`, so their memory is left untouched.
+    pub fn secure_clear(&mut self) {
+        match self {
+            Sparse(Cow::Owned(s)) => s.secure_clear(),
+            Dense(Cow::Owned(d)) => d.secure_clear(),
+            Sparse(Cow::Borrowed(_)) | Dense(Cow::Borrowed(_)) => *self = Sparse(Cow::Owned(SparsePolynomial::zero())),
+        }
+    }
+
+    /// Checks that `self` is in canonical form, i.e. that it stores no zero leading coefficients
+    /// (for dense polynomials) or zero terms (for sparse polynomials).
+    pub fn is_canonical(&self) -> bool {
+        match self {
+            Sparse(s) => s.coeffs().all(|(_, c)| !c.is_zero()),
+            Dense(d) => !matches!(d.coeffs.last(), Some(c) if c.is_zero()),
+        }
+    }
+
     /// Return the degree of `self.
     pub fn degree(&self) -> usize {
         match self {
@@ -151,11 +267,25 @@ impl<'a, F: Field> Polynomial<'a, F> {
         }
     }
 
+    /// Returns the coefficient of the highest-degree term of `self`,
+    /// or `None` if `self` is the zero polynomial.
     #[inline]
-    pub fn leading_coefficient(&self) -> Option<&F> {
+    pub fn leading_coefficient(&self) -> Option<F> {
+        if self.is_zero() {
+            return None;
+        }
+        match self {
+            Sparse(p) => p.coeffs().last().map(|(_, c)| *c),
+            Dense(p) => p.last().copied(),
+        }
+    }
+
+    /// Returns the coefficient of the degree-0 term of `self`.
+    #[inline]
+    pub fn constant_term(&self) -> F {
         match self {
-            Sparse(p) => p.coeffs().last().map(|(_, c)| c),
-            Dense(p) => p.last(),
+            Sparse(p) => p.coeffs().next().filter(|(i, _)| **i == 0).map_or_else(F::zero, |(_, c)| *c),
+            Dense(p) => p.first().copied().unwrap_or_else(F::zero),
         }
     }
 
@@ -191,11 +321,43 @@ impl<'a, F: Field> Polynomial<'a, F> {
         }
     }
 
+    /// Returns `true` if `self` is stored in dense form.
+    #[inline]
+    pub fn is_dense(&self) -> bool {
+        matches!(self, Dense(_))
+    }
+
+    /// Returns `true` if `self` is stored in sparse form.
+    #[inline]
+    pub fn is_sparse(&self) -> bool {
+        matches!(self, Sparse(_))
+    }
+
+    /// Returns the number of coefficients stored by `self`, which is the length of the coefficient vector
+    /// for a dense polynomial, including any trailing zeros, and the number of terms for a sparse polynomial.
+    #[inline]
+    pub fn num_stored_coefficients(&self) -> usize {
+        match self {
+            Dense(p) => p.coeffs.len(),
+            Sparse(p) => p.num_terms(),
+        }
+    }
+
+    /// Convert `self` into a polynomial that owns its coefficients, cloning them if they are borrowed.
+    pub fn into_owned(self) -> Polynomial<'static, F> {
+        match self {
+            Sparse(p) => Sparse(Cow::Owned(p.into_owned())),
+            Dense(p) => Dense(Cow::Owned(p.into_owned())),
+        }
+    }
+
     #[inline]
     pub fn into_dense(&self) -> DensePolynomial<F> {
         self.clone().into()
     }
 
+    /// Evaluates `self` at the given `point` in the field.
+    /// At zero, both dense and sparse polynomials return their constant term directly.
     #[inline]
     pub fn evaluate(&self, point: F) -> F {
         match self {
@@ -204,6 +366,50 @@ impl<'a, F: Field> Polynomial<'a, F> {
         }
     }
 
+    /// Evaluate `self` at each of `points`.
+    ///
+    /// For sparse polynomials, the terms are visited in ascending order of degree, and each power of a point
+    /// is obtained from the previous one by multiplying by the point raised to the gap between their degrees.
+    /// The gaps are computed once and shared across all points, so that each term costs about
+    /// `log(gap)` squarings per point instead of `log(degree)`.
+    pub fn evaluate_batch_sparse_aware(&self, points: &[F]) -> Vec<F> {
+        match self {
+            Sparse(p) => {
+                let mut previous = 0;
+                let gaps = p
+                    .coeffs()
+                    .map(|(i, c)| {
+                        let gap = (*i - previous) as u64;
+                        previous = *i;
+                        (gap, *c)
+                    })
+                    .collect::<Vec<_>>();
+                cfg_iter!(points)
+                    .map(|point| {
+                        let mut power = F::one();
+                        gaps.iter()
+                            .map(|(gap, c)| {
+                                power *= point.pow([*gap]);
+                                power * c
+                            })
+                            .sum()
+                    })
+                    .collect()
+            }
+            Dense(p) => cfg_iter!(points).map(|point| p.evaluate(*point)).collect(),
+        }
+    }
+
+    /// Iterate over the `(degree, coefficient)` pairs of the terms of `self` with nonzero coefficients,
+    /// in ascending order of degree, for both dense and sparse polynomials.
+    pub fn iter_nonzero_terms(&self) -> impl Iterator<Item = (usize, F)> + '_ {
+        let terms: Box<dyn Iterator<Item = (usize, &F)>> = match self {
+            Sparse(p) => Box::new(p.coeffs().map(|(i, c)| (*i, c))),
+            Dense(p) => Box::new(p.coeffs.iter().enumerate()),
+        };
+        terms.filter(|(_, c)| !c.is_zero()).map(|(i, c)| (i, *c))
+    }
+
     pub fn coeffs(&'a self) -> Box<dyn Iterator<Item = (usize, &'a F)> + 'a> {
         match self {
             Sparse(p) => Box::new(p.coeffs().map(|(c, f)| (*c, f))),
@@ -223,33 +429,104 @@ impl<'a, F: Field> Polynomial<'a, F> {
             // Now we know that self.degree() >= divisor.degree();
             let mut quotient = vec![F::zero(); self.degree() - divisor.degree() + 1];
             let mut remainder: DensePolynomial<F> = self.clone().into();
-            // Can unwrap here because we know self is not zero.
-            let divisor_leading_inv = divisor.leading_coefficient().unwrap().inverse().unwrap();
-            while !remainder.is_zero() && remainder.degree() >= divisor.degree() {
-                let cur_q_coeff = *remainder.coeffs.last().unwrap() * divisor_leading_inv;
-                let cur_q_degree = remainder.degree() - divisor.degree();
-                quotient[cur_q_degree] = cur_q_coeff;
-
-                if let Sparse(p) = divisor {
-                    for (i, div_coeff) in p.coeffs() {
-                        remainder[cur_q_degree + i] -= &(cur_q_coeff * div_coeff);
-                    }
-                } else if let Dense(p) = divisor {
-                    for (i, div_coeff) in p.iter().enumerate() {
-                        remainder[cur_q_degree + i] -= &(cur_q_coeff * div_coeff);
-                    }
-                }
+            Self::reduce(&mut remainder, divisor, |degree, coeff| quotient[degree] = coeff);
+            Some((DensePolynomial::from_coefficients_vec(quotient), remainder))
+        }
+    }
+
+    /// Compute the remainder of `self` modulo `modulus`, without computing the quotient.
+    /// When `modulus` is `x^n - 1`, this is done by reducing the exponents of `self` modulo `n`.
+    pub fn rem(&self, modulus: &Self) -> DensePolynomial<F> {
+        if modulus.is_zero() {
+            panic!("Dividing by zero polynomial")
+        } else if self.is_zero() {
+            DensePolynomial::zero()
+        } else if let Some(n) = modulus.as_x_n_minus_one() {
+            let mut remainder = vec![F::zero(); n];
+            self.coeffs().for_each(|(i, c)| remainder[i % n] += c);
+            DensePolynomial::from_coefficients_vec(remainder)
+        } else if self.degree() < modulus.degree() {
+            self.clone().into()
+        } else {
+            let mut remainder: DensePolynomial<F> = self.clone().into();
+            Self::reduce(&mut remainder, modulus, |_, _| {});
+            remainder
+        }
+    }
+
+    /// Returns `Some(n)` if `self` is `x^n - 1` for some `n > 0`.
+    fn as_x_n_minus_one(&self) -> Option<usize> {
+        let mut terms = self.iter_nonzero_terms();
+        match (terms.next(), terms.next(), terms.next()) {
+            (Some((0, c0)), Some((n, cn)), None) if c0 == -F::one() && cn.is_one() => Some(n),
+            _ => None,
+        }
+    }
 
-                while let Some(true) = remainder.coeffs.last().map(|c| c.is_zero()) {
-                    remainder.coeffs.pop();
+    /// Reduce `remainder` modulo the nonzero polynomial `divisor` by long division,
+    /// passing the degree and coefficient of each quotient term to `on_quotient_term`.
+    fn reduce(remainder: &mut DensePolynomial<F>, divisor: &Self, mut on_quotient_term: impl FnMut(usize, F)) {
+        // Can unwrap here because we know divisor is not zero.
+        let divisor_leading_inv = divisor.leading_coefficient().unwrap().inverse().unwrap();
+        while !remainder.is_zero() && remainder.degree() >= divisor.degree() {
+            let cur_q_coeff = *remainder.coeffs.last().unwrap() * divisor_leading_inv;
+            let cur_q_degree = remainder.degree() - divisor.degree();
+            on_quotient_term(cur_q_degree, cur_q_coeff);
+
+            if let Sparse(p) = divisor {
+                for (i, div_coeff) in p.coeffs() {
+                    remainder[cur_q_degree + i] -= &(cur_q_coeff * div_coeff);
+                }
+            } else if let Dense(p) = divisor {
+                for (i, div_coeff) in p.iter().enumerate() {
+                    remainder[cur_q_degree + i] -= &(cur_q_coeff * div_coeff);
                 }
             }
-            Some((DensePolynomial::from_coefficients_vec(quotient), remainder))
+
+            while let Some(true) = remainder.coeffs.last().map(|c| c.is_zero()) {
+                remainder.coeffs.pop();
+            }
         }
     }
 }
 
 impl<F: PrimeField> Polynomial<'_, F> {
+    /// Returns whether a polynomial of degree `degree` with `num_terms` nonzero terms is cheaper
+    /// to store in sparse form, i.e. whether at most a quarter of its coefficients are nonzero.
+    pub fn prefers_sparse(num_terms: usize, degree: usize) -> bool {
+        num_terms.saturating_mul(4) <= degree.saturating_add(1)
+    }
+
+    /// Compute `self + other`, choosing the representation of the result according to
+    /// [`Self::prefers_sparse`].
+    pub fn add(&self, other: &Polynomial<'_, F>) -> Polynomial<'static, F> {
+        let sum = match (self, other) {
+            (Sparse(a), Sparse(b)) => {
+                let mut sum = a.clone().into_owned();
+                sum += b.as_ref();
+                Sparse(Cow::Owned(sum))
+            }
+            (Dense(a), Dense(b)) => Dense(Cow::Owned(a.as_ref() + b.as_ref())),
+            (Dense(d), Sparse(s)) | (Sparse(s), Dense(d)) => {
+                let mut coeffs = d.coeffs.clone();
+                let num_coeffs = s.degree().checked_add(1).expect("Degree overflow when adding polynomials");
+                if coeffs.len() < num_coeffs {
+                    coeffs.resize(num_coeffs, F::zero());
+                }
+                s.coeffs().for_each(|(i, c)| coeffs[*i] += c);
+                Dense(Cow::Owned(DensePolynomial::from_coefficients_vec(coeffs)))
+            }
+        };
+        let num_terms = sum.iter_nonzero_terms().count();
+        match sum {
+            Sparse(s) if !Self::prefers_sparse(num_terms, s.degree()) => Dense(Cow::Owned(s.into_owned().into())),
+            Dense(d) if Self::prefers_sparse(num_terms, d.degree()) => {
+                Sparse(Cow::Owned(SparsePolynomial::from_coefficients(d.coeffs.iter().copied().enumerate())))
+            }
+            sum => sum,
+        }
+    }
+
     /// Construct `Evaluations` by evaluating a polynomial over the domain `domain`.
     pub fn evaluate_over_domain(poly: impl Into<Self>, domain: EvaluationDomain<F>) -> Evaluations<F> {
         let poly = poly.into();
@@ -258,14 +535,7 @@ impl<F: PrimeField> Polynomial<'_, F> {
 
     fn eval_over_domain_helper(self, domain: EvaluationDomain<F>) -> Evaluations<F> {
         match self {
-            Sparse(Cow::Borrowed(s)) => {
-                let evals = domain.elements().map(|elem| s.evaluate(elem)).collect();
-                Evaluations::from_vec_and_domain(evals, domain)
-            }
-            Sparse(Cow::Owned(s)) => {
-                let evals = domain.elements().map(|elem| s.evaluate(elem)).collect();
-                Evaluations::from_vec_and_domain(evals, domain)
-            }
+            Sparse(s) => s.evaluate_over_domain_by_ref(domain),
             Dense(Cow::Borrowed(d)) => {
                 if d.degree() >= domain.size() {
                     d.coeffs
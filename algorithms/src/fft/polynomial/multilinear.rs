@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A multilinear polynomial represented by its evaluations over the boolean hypercube.
+
+use snarkvm_fields::Field;
+use snarkvm_utilities::{cfg_chunks, serialize::*};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Stores a multilinear polynomial in `num_vars` variables by its evaluations over `{0,1}^num_vars`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearPolynomial<F: Field> {
+    /// The evaluation at `(b_0, ..., b_{k-1})` is stored at location `sum_i b_i * 2^i` in `self.evaluations`.
+    evaluations: Vec<F>,
+    /// The number of variables.
+    num_vars: usize,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    /// Constructs the multilinear extension of `evaluations` over the boolean hypercube.
+    /// Returns `None` if the number of evaluations is not a power of two.
+    pub fn from_evaluations(evaluations: Vec<F>) -> Option<Self> {
+        if !evaluations.len().is_power_of_two() {
+            return None;
+        }
+        let num_vars = evaluations.len().trailing_zeros() as usize;
+        Some(Self { evaluations, num_vars })
+    }
+
+    /// Returns the number of variables of `self`.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the evaluations of `self` over the boolean hypercube.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Evaluates `self` at `point`, by folding the evaluations along one variable at a time.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "The point must have one coordinate per variable");
+        let mut evaluations = self.evaluations.clone();
+        for x in point {
+            // Fix the lowest remaining variable to `x`.
+            evaluations = cfg_chunks!(evaluations, 2).map(|e| e[0] + (e[1] - e[0]) * x).collect();
+        }
+        evaluations[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultilinearPolynomial;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn from_evaluations_requires_power_of_two() {
+        assert!(MultilinearPolynomial::<Fr>::from_evaluations(vec![]).is_none());
+        assert!(MultilinearPolynomial::<Fr>::from_evaluations(vec![Fr::one(); 3]).is_none());
+        assert_eq!(MultilinearPolynomial::from_evaluations(vec![Fr::one()]).unwrap().num_vars(), 0);
+        assert_eq!(MultilinearPolynomial::from_evaluations(vec![Fr::one(); 8]).unwrap().num_vars(), 3);
+    }
+
+    #[test]
+    fn evaluate_matches_multilinear_extension() {
+        let rng = &mut TestRng::default();
+        for num_vars in 0..6 {
+            let evaluations: Vec<Fr> = (0..1 << num_vars).map(|_| Fr::rand(rng)).collect();
+            let poly = MultilinearPolynomial::from_evaluations(evaluations.clone()).unwrap();
+
+            // On the boolean hypercube, `poly` agrees with its evaluations.
+            for (b, evaluation) in evaluations.iter().enumerate() {
+                let point: Vec<Fr> =
+                    (0..num_vars).map(|i| if (b >> i) & 1 == 1 { Fr::one() } else { Fr::zero() }).collect();
+                assert_eq!(poly.evaluate(&point), *evaluation);
+            }
+
+            // Elsewhere, `poly` is the sum of the evaluations weighted by the multilinear Lagrange basis.
+            let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let expected: Fr = evaluations
+                .iter()
+                .enumerate()
+                .map(|(b, e)| {
+                    let weight: Fr = point
+                        .iter()
+                        .enumerate()
+                        .map(|(i, x)| if (b >> i) & 1 == 1 { *x } else { Fr::one() - x })
+                        .product();
+                    weight * e
+                })
+                .sum();
+            assert_eq!(poly.evaluate(&point), expected);
+        }
+    }
+}
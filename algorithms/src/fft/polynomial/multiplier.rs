@@ -70,7 +70,11 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
         if self.polynomials.is_empty() && self.evaluations.is_empty() {
             Some(DensePolynomial::zero())
         } else {
-            let degree = self.polynomials.iter().map(|(_, p)| p.degree() + 1).sum::<usize>();
+            let degree = self
+                .polynomials
+                .iter()
+                .try_fold(0usize, |acc, (_, p)| acc.checked_add(p.degree())?.checked_add(1))
+                .expect("Degree overflow when multiplying polynomials");
             let domain = EvaluationDomain::new(degree)?;
             if self.evaluations.iter().any(|(_, e)| e.domain() != domain) {
                 None
@@ -14,21 +14,98 @@
 
 //! A sparse polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
-use snarkvm_fields::{Field, PrimeField};
+use crate::fft::{EvaluationDomain, Evaluations};
+use snarkvm_fields::{FftField, FftParameters, Field, PrimeField};
 use snarkvm_utilities::serialize::*;
 
+use rand::Rng;
 use std::{collections::BTreeMap, fmt};
 
 /// Stores a sparse polynomial in coefficient form.
-#[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(serialize = "", deserialize = "F: FftField"), try_from = "SparseTerms<F>")
+)]
 #[must_use]
 pub struct SparsePolynomial<F: Field> {
     /// The coefficient a_i of `x^i` is stored as (i, a_i) in `self.coeffs`.
     /// the entries in `self.coeffs` are sorted in increasing order of `i`.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_terms"))]
     coeffs: BTreeMap<usize, F>,
 }
 
+/// With the `serde` feature, the terms of a sparse polynomial are serialized as a sequence of
+/// `(degree, coefficient)` pairs in increasing order of degree, as in the canonical format.
+#[cfg(feature = "serde")]
+fn serialize_terms<F: Field, S: serde::Serializer>(
+    coeffs: &BTreeMap<usize, F>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(coeffs)
+}
+
+/// The terms of a sparse polynomial as deserialized by `serde`, before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "")]
+struct SparseTerms<F: Field> {
+    coeffs: Vec<(usize, F)>,
+}
+
+#[cfg(feature = "serde")]
+impl<F: FftField> TryFrom<SparseTerms<F>> for SparsePolynomial<F> {
+    type Error = SerializationError;
+
+    /// As when deserializing with [`Validate::Yes`], the degrees must be strictly increasing,
+    /// and the result must pass [`Valid::check`].
+    fn try_from(terms: SparseTerms<F>) -> Result<Self, Self::Error> {
+        if terms.coeffs.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err(SerializationError::InvalidData);
+        }
+        let polynomial = Self { coeffs: terms.coeffs.into_iter().collect() };
+        polynomial.check()?;
+        Ok(polynomial)
+    }
+}
+
+impl<F: FftField> Valid for SparsePolynomial<F> {
+    /// Check that every coefficient is nonzero and that the degree is at most [`SparsePolynomial::max_degree`].
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.coeffs.values().any(|c| c.is_zero()) || self.degree() > Self::max_degree() {
+            return Err(SerializationError::InvalidData);
+        }
+        F::batch_check(self.coeffs.values())
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for SparsePolynomial<F> {
+    /// Deserializes a sparse polynomial from `len || degree 1 || coefficient 1 || ... || degree n || coefficient n`.
+    /// When validating, the degrees must be strictly increasing, and the result must pass [`Valid::check`].
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+        let mut coeffs = BTreeMap::new();
+        for _ in 0..len {
+            let degree = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+            let coeff = F::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+            if validate == Validate::Yes && matches!(coeffs.keys().next_back(), Some(last) if *last >= degree) {
+                return Err(SerializationError::InvalidData);
+            }
+            coeffs.insert(degree, coeff);
+        }
+        let polynomial = Self { coeffs };
+        if let Validate::Yes = validate {
+            polynomial.check()?;
+        }
+        Ok(polynomial)
+    }
+}
+
 impl<F: Field> fmt::Debug for SparsePolynomial<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         for (i, coeff) in self.coeffs.iter().filter(|(_, c)| !c.is_zero()) {
@@ -55,6 +132,21 @@ impl<F: Field> SparsePolynomial<F> {
         self.coeffs.is_empty() || self.coeffs.iter().all(|(_, c)| c.is_zero())
     }
 
+    /// Returns the number of terms stored in `self`.
+    pub fn num_terms(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// Checks if the given polynomial is the constant `1`, ignoring any terms with zero coefficients.
+    pub fn is_one(&self) -> bool {
+        matches!(self.coeffs.get(&0), Some(c) if c.is_one()) && self.is_constant()
+    }
+
+    /// Checks if the given polynomial is constant (including zero), ignoring any terms with zero coefficients.
+    pub fn is_constant(&self) -> bool {
+        self.coeffs.range(1..).all(|(_, c)| c.is_zero())
+    }
+
     /// Constructs a new polynomial from a list of coefficients.
     pub fn from_coefficients_slice(coeffs: &[(usize, F)]) -> Self {
         Self::from_coefficients(coeffs.iter().copied())
@@ -66,10 +158,43 @@ impl<F: Field> SparsePolynomial<F> {
         Self { coeffs }
     }
 
+    /// Outputs a polynomial with `num_terms` nonzero terms of degree at most `max_degree`.
+    /// The exponents are sampled uniformly without replacement, and each coefficient is
+    /// sampled uniformly at random from the nonzero elements of the field `F`.
+    pub fn rand<R: Rng>(num_terms: usize, max_degree: usize, rng: &mut R) -> Self {
+        let num_exponents = max_degree.checked_add(1).expect("Degree overflow when sampling a polynomial");
+        assert!(num_terms <= num_exponents, "Cannot sample {num_terms} distinct exponents up to {max_degree}");
+        let coeffs = rand::seq::index::sample(rng, num_exponents, num_terms)
+            .into_iter()
+            .map(|i| {
+                let mut c = F::rand(rng);
+                while c.is_zero() {
+                    c = F::rand(rng);
+                }
+                (i, c)
+            })
+            .collect::<Vec<_>>();
+        Self::from_coefficients(coeffs)
+    }
+
     pub fn coeffs(&self) -> impl Iterator<Item = (&usize, &F)> {
         self.coeffs.iter()
     }
 
+    /// Returns the coefficient of `x^degree` in `self`.
+    pub fn coefficient(&self, degree: usize) -> F {
+        self.coeffs.get(&degree).copied().unwrap_or_else(F::zero)
+    }
+
+    /// Set the coefficient of `x^degree` in `self` to `value`, removing the term if `value` is zero.
+    pub fn set_coefficient(&mut self, degree: usize, value: F) {
+        if value.is_zero() {
+            self.coeffs.remove(&degree);
+        } else {
+            self.coeffs.insert(degree, value);
+        }
+    }
+
     /// Returns the degree of the polynomial.
     pub fn degree(&self) -> usize {
         if self.is_zero() {
@@ -85,6 +210,8 @@ impl<F: Field> SparsePolynomial<F> {
     pub fn evaluate(&self, point: F) -> F {
         if self.is_zero() {
             return F::zero();
+        } else if point.is_zero() {
+            return self.coefficient(0);
         }
         let mut total = F::zero();
         for (i, c) in &self.coeffs {
@@ -93,7 +220,24 @@ impl<F: Field> SparsePolynomial<F> {
         total
     }
 
+    /// Overwrite every coefficient of `self` with zero before removing it,
+    /// leaving `self` as the zero polynomial.
+    pub fn secure_clear(&mut self) {
+        self.coeffs.values_mut().for_each(|c| *c = F::zero());
+        // Prevent the compiler from eliding the writes above as dead stores.
+        std::hint::black_box(&mut self.coeffs);
+        self.coeffs.clear();
+    }
+
+    /// Multiply `self` by `x^n`, by increasing every exponent by `n`.
+    /// Panics if the resulting degree overflows `usize`.
+    pub fn mul_by_xn(&self, n: usize) -> Self {
+        let shift = |i: &usize| i.checked_add(n).expect("Degree overflow when multiplying by x^n");
+        Self { coeffs: self.coeffs.iter().map(|(i, c)| (shift(i), *c)).collect() }
+    }
+
     /// Perform a naive n^2 multiplicatoin of `self` by `other`.
+    /// Panics if the resulting degree overflows `usize`.
     pub fn mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
             SparsePolynomial::zero()
@@ -101,7 +245,8 @@ impl<F: Field> SparsePolynomial<F> {
             let mut result = std::collections::BTreeMap::new();
             for (i, self_coeff) in self.coeffs.iter() {
                 for (j, other_coeff) in other.coeffs.iter() {
-                    let cur_coeff = result.entry(i + j).or_insert_with(F::zero);
+                    let degree = i.checked_add(*j).expect("Degree overflow when multiplying polynomials");
+                    let cur_coeff = result.entry(degree).or_insert_with(F::zero);
                     *cur_coeff += *self_coeff * other_coeff;
                 }
             }
@@ -110,21 +255,27 @@ impl<F: Field> SparsePolynomial<F> {
     }
 }
 
+impl<F: FftField> SparsePolynomial<F> {
+    /// The largest degree of a term that is accepted when deserializing a sparse polynomial with validation.
+    /// This is the degree `2^F::FftParameters::TWO_ADICITY` of the vanishing polynomial of the largest
+    /// evaluation domain over `F`, so that every polynomial arising from such a domain is accepted.
+    pub fn max_degree() -> usize {
+        1usize.checked_shl(F::FftParameters::TWO_ADICITY).unwrap_or(usize::MAX)
+    }
+}
+
 impl<F: PrimeField> SparsePolynomial<F> {
-    /// Evaluate `self` over `domain`.
+    /// Evaluate `self` over `domain`, using [`EvaluationDomain::fft_sparse`].
     pub fn evaluate_over_domain_by_ref(&self, domain: EvaluationDomain<F>) -> Evaluations<F> {
-        let poly: Polynomial<'_, F> = self.into();
-        Polynomial::<F>::evaluate_over_domain(poly, domain)
-        // unimplemented!("current implementation does not produce evals in correct order")
+        Evaluations::from_vec_and_domain(domain.fft_sparse(self), domain)
     }
 
     /// Evaluate `self` over `domain`.
     pub fn evaluate_over_domain(self, domain: EvaluationDomain<F>) -> Evaluations<F> {
-        let poly: Polynomial<'_, F> = self.into();
-        Polynomial::<F>::evaluate_over_domain(poly, domain)
-        // unimplemented!("current implementation does not produce evals in correct order")
+        self.evaluate_over_domain_by_ref(domain)
     }
 }
+
 impl<F: PrimeField> core::ops::MulAssign<F> for SparsePolynomial<F> {
     fn mul_assign(&mut self, other: F) {
         if other.is_zero() {
@@ -160,20 +311,32 @@ impl<'a, F: PrimeField> core::ops::AddAssign<&'a Self> for SparsePolynomial<F> {
 
 impl<'a, F: PrimeField> core::ops::AddAssign<(F, &'a Self)> for SparsePolynomial<F> {
     fn add_assign(&mut self, (f, other): (F, &'a Self)) {
-        let mut result = other.clone();
-        for (i, coeff) in self.coeffs.iter() {
-            let cur_coeff = result.coeffs.entry(*i).or_insert_with(F::zero);
+        for (i, coeff) in other.coeffs.iter() {
+            let cur_coeff = self.coeffs.entry(*i).or_insert_with(F::zero);
             *cur_coeff += f * coeff;
         }
-        *self = Self::from_coefficients(result.coeffs.into_iter().filter(|(_, f)| !f.is_zero()))
+        self.coeffs.retain(|_, f| !f.is_zero());
+    }
+}
+
+impl<F: Field> core::ops::Neg for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    #[inline]
+    fn neg(mut self) -> SparsePolynomial<F> {
+        for coeff in self.coeffs.values_mut() {
+            *coeff = -*coeff;
+        }
+        self
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::fft::{DensePolynomial, EvaluationDomain, SparsePolynomial};
+    use crate::fft::{DensePolynomial, EvaluationDomain, Polynomial, SparsePolynomial};
     use snarkvm_curves::bls12_377::Fr;
-    use snarkvm_fields::One;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
 
     #[test]
     fn evaluate_over_domain() {
@@ -191,4 +354,204 @@ mod tests {
             assert_eq!(evals2.interpolate(), dense_poly);
         }
     }
+
+    #[test]
+    fn evaluate_at_zero() {
+        let two = Fr::one() + Fr::one();
+        let with_constant = SparsePolynomial::from_coefficients(vec![(0, two), (3, Fr::one())]);
+        let without_constant = SparsePolynomial::from_coefficients(vec![(2, two), (3, Fr::one())]);
+        assert_eq!(with_constant.evaluate(Fr::zero()), two);
+        assert_eq!(without_constant.evaluate(Fr::zero()), Fr::zero());
+        assert_eq!(SparsePolynomial::<Fr>::zero().evaluate(Fr::zero()), Fr::zero());
+
+        for sparse in [with_constant, without_constant] {
+            let dense: DensePolynomial<Fr> = sparse.clone().into();
+            assert_eq!(dense.evaluate(Fr::zero()), sparse.evaluate(Fr::zero()));
+            assert_eq!(Polynomial::from(dense).evaluate(Fr::zero()), sparse.evaluate(Fr::zero()));
+            assert_eq!(Polynomial::from(sparse.clone()).evaluate(Fr::zero()), sparse.evaluate(Fr::zero()));
+        }
+    }
+
+    #[test]
+    fn evaluate_over_domain_matches_dense() {
+        let mut rng = TestRng::default();
+        for log_size in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for num_terms in [0, 1, 3] {
+                // Include degrees beyond the domain size, which wrap around.
+                let sparse_poly = SparsePolynomial::<Fr>::rand(num_terms, 4 << log_size, &mut rng);
+                let evals = sparse_poly.evaluate_over_domain_by_ref(domain);
+                let dense_poly: DensePolynomial<Fr> = sparse_poly.clone().into();
+                assert_eq!(evals, dense_poly.evaluate_over_domain(domain));
+                assert_eq!(evals, sparse_poly.evaluate_over_domain(domain));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Degree overflow")]
+    fn mul_by_xn_overflow() {
+        let p = SparsePolynomial::from_coefficients([(usize::MAX / 2 + 1, Fr::one())]);
+        let _ = p.mul_by_xn(usize::MAX / 2 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Degree overflow")]
+    fn mul_overflow() {
+        let p = SparsePolynomial::from_coefficients([(usize::MAX / 2 + 1, Fr::one())]);
+        let _ = p.mul(&p);
+    }
+
+    #[test]
+    #[should_panic(expected = "Degree overflow")]
+    fn densify_overflow() {
+        let p = SparsePolynomial::from_coefficients([(usize::MAX, Fr::one())]);
+        let _ = DensePolynomial::from(p);
+    }
+
+    #[test]
+    fn rand() {
+        let rng = &mut TestRng::default();
+        for max_degree in [0, 1, 10, 100] {
+            for num_terms in [0, 1, max_degree / 2, max_degree + 1] {
+                let poly = SparsePolynomial::<Fr>::rand(num_terms, max_degree, rng);
+                assert_eq!(poly.coeffs().count(), num_terms);
+                assert!(poly.degree() <= max_degree);
+            }
+        }
+    }
+
+    #[test]
+    fn add_assign_scaled() {
+        let rng = &mut TestRng::default();
+        let a = SparsePolynomial::<Fr>::rand(5, 20, rng);
+        let b = SparsePolynomial::<Fr>::rand(5, 20, rng);
+        let f = Fr::rand(rng);
+        let mut sum = a.clone();
+        sum += (f, &b);
+        let point = Fr::rand(rng);
+        assert_eq!(sum.evaluate(point), a.evaluate(point) + f * b.evaluate(point));
+
+        // Terms that cancel are removed.
+        let mut zero = a.clone();
+        zero += (-Fr::one(), &a);
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn scale_and_negate() {
+        let rng = &mut TestRng::default();
+        let a = SparsePolynomial::<Fr>::rand(5, 20, rng);
+        let f = Fr::rand(rng);
+        let point = Fr::rand(rng);
+
+        let mut scaled = a.clone();
+        scaled *= f;
+        assert_eq!(scaled.evaluate(point), f * a.evaluate(point));
+        assert_eq!(scaled.coeffs().count(), a.coeffs().count());
+        assert_eq!(&a * f, scaled);
+
+        // Scaling by zero leaves no terms.
+        let mut zero = a.clone();
+        zero *= Fr::zero();
+        assert!(zero.is_zero());
+        assert_eq!(zero.coeffs().count(), 0);
+
+        let negated = -a.clone();
+        assert_eq!(negated.evaluate(point), -a.evaluate(point));
+        let mut sum = negated;
+        sum += &a;
+        assert!(sum.is_zero());
+        assert!((-SparsePolynomial::<Fr>::zero()).is_zero());
+    }
+
+    #[test]
+    fn evaluate_batch_sparse_aware() {
+        let rng = &mut TestRng::default();
+        let points = [Fr::zero(), Fr::one(), Fr::rand(rng), Fr::rand(rng)];
+        let sparse = SparsePolynomial::<Fr>::rand(10, 1000, rng);
+        let with_constant = SparsePolynomial::from_coefficients([(0, Fr::rand(rng)), (1, Fr::rand(rng))]);
+        let dense = DensePolynomial::<Fr>::rand(20, rng);
+        let polynomials = [
+            Polynomial::from(&sparse),
+            Polynomial::from(&with_constant),
+            Polynomial::from(SparsePolynomial::zero()),
+            Polynomial::from(&dense),
+        ];
+        for polynomial in polynomials {
+            let expected = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+            assert_eq!(polynomial.evaluate_batch_sparse_aware(&points), expected);
+        }
+    }
+
+    #[test]
+    fn deserialize_with_validation() {
+        use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+        let rng = &mut TestRng::default();
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let encode = |terms: &[(usize, Fr)]| {
+            let mut bytes = Vec::new();
+            (terms.len() as u64).serialize_compressed(&mut bytes).unwrap();
+            for (degree, coeff) in terms {
+                degree.serialize_compressed(&mut bytes).unwrap();
+                coeff.serialize_compressed(&mut bytes).unwrap();
+            }
+            bytes
+        };
+        let decode =
+            |bytes: &[u8], validate| SparsePolynomial::<Fr>::deserialize_with_mode(bytes, Compress::Yes, validate);
+
+        let p = SparsePolynomial::from_coefficients([(1, a), (5, b)]);
+        let mut bytes = Vec::new();
+        p.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes, encode(&[(1, a), (5, b)]));
+        assert_eq!(decode(&bytes, Validate::Yes).unwrap(), p);
+        let largest = encode(&[(SparsePolynomial::<Fr>::max_degree(), a)]);
+        assert_eq!(decode(&largest, Validate::Yes).unwrap().degree(), SparsePolynomial::<Fr>::max_degree());
+        // The vanishing polynomial of a large domain is accepted.
+        let vanishing = EvaluationDomain::<Fr>::new(1 << 29).unwrap().vanishing_polynomial();
+        let mut bytes = Vec::new();
+        vanishing.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(decode(&bytes, Validate::Yes).unwrap(), vanishing);
+
+        // Degrees that are not strictly increasing, zero coefficients, and huge degrees are rejected.
+        for terms in [
+            vec![(5, b), (1, a)],
+            vec![(1, a), (1, b)],
+            vec![(1, a), (2, Fr::zero())],
+            vec![(SparsePolynomial::<Fr>::max_degree() + 1, a)],
+        ] {
+            assert!(decode(&encode(&terms), Validate::Yes).is_err(), "{terms:?}");
+            assert!(decode(&encode(&terms), Validate::No).is_ok(), "{terms:?}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_with_validation() {
+        let rng = &mut TestRng::default();
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let encode = |terms: &[(usize, Fr)]| serde_json::json!({ "coeffs": terms }).to_string();
+        let decode = |json: &str| serde_json::from_str::<SparsePolynomial<Fr>>(json);
+
+        let p = SparsePolynomial::from_coefficients([(1, a), (5, b)]);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, encode(&[(1, a), (5, b)]));
+        assert_eq!(decode(&json).unwrap(), p);
+        assert_eq!(bincode::deserialize::<SparsePolynomial<Fr>>(&bincode::serialize(&p).unwrap()).unwrap(), p);
+        let largest = encode(&[(SparsePolynomial::<Fr>::max_degree(), a)]);
+        assert_eq!(decode(&largest).unwrap().degree(), SparsePolynomial::<Fr>::max_degree());
+
+        // As in the canonical format, degrees that are not strictly increasing, zero coefficients,
+        // and huge degrees are rejected.
+        for terms in [
+            vec![(5, b), (1, a)],
+            vec![(1, a), (1, b)],
+            vec![(1, a), (2, Fr::zero())],
+            vec![(SparsePolynomial::<Fr>::max_degree() + 1, a)],
+        ] {
+            assert!(decode(&encode(&terms)).is_err(), "{terms:?}");
+        }
+    }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, Evaluations};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
@@ -341,3 +341,143 @@ fn evaluate_over_domain() {
         }
     }
 }
+
+#[test]
+fn interpolate_with_pc() {
+    let rng = &mut TestRng::default();
+
+    let max_domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+    let max_pc = max_domain.precompute_ifft();
+    for domain_size in (1..=10).map(|i| 2usize.pow(i)) {
+        let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
+        let pc = max_pc.precomputation_for_subdomain(&domain).unwrap();
+        for num_evals in [domain_size / 2, domain_size] {
+            let evals = Evaluations::from_vec_and_domain((0..num_evals).map(|_| Fr::rand(rng)).collect(), domain);
+            let expected = evals.interpolate_by_ref();
+            assert_eq!(evals.interpolate_with_pc_by_ref(&pc), expected);
+            assert_eq!(evals.interpolate_with_pc(&pc), expected);
+        }
+    }
+}
+
+#[test]
+fn evaluations_square_and_pow() {
+    let rng = &mut TestRng::default();
+    for degree in [0, 1, 7, 20] {
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        for exp in [0u64, 1, 2, 3] {
+            // The domain must be large enough to hold `p^exp`, which has degree `exp * degree`.
+            let domain = EvaluationDomain::<Fr>::new(exp as usize * degree + 1).unwrap();
+            let evaluations = p.clone().evaluate_over_domain(domain);
+
+            let mut expected = DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
+            for _ in 0..exp {
+                expected = &expected * &p;
+            }
+            let expected = expected.evaluate_over_domain(domain);
+
+            assert_eq!(evaluations.pow(exp), expected);
+            if exp == 2 {
+                assert_eq!(evaluations.square(), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn evaluations_map_and_zip_map() {
+    let rng = &mut TestRng::default();
+    let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+    let p = DensePolynomial::<Fr>::rand(5, rng);
+    let q = DensePolynomial::<Fr>::rand(7, rng);
+    let p_evals = p.clone().evaluate_over_domain(domain);
+    let q_evals = q.clone().evaluate_over_domain(domain);
+
+    // The gate `p * q - p + 3` is evaluated pointwise.
+    let three = Fr::from(3u64);
+    let gate = p_evals.zip_map(&q_evals, |a, b| a * b - a + three);
+    assert_eq!(gate.domain(), domain);
+    let expected = &(&(&p * &q) - &p) + &DensePolynomial::from_coefficients_vec(vec![three]);
+    assert_eq!(gate.interpolate(), expected);
+
+    assert_eq!(p_evals.map(|a| a.square()), p_evals.square());
+    assert_eq!(p_evals.map(|a| a + three).zip_map(&p_evals, |a, b| a - b).evaluations, vec![three; 16]);
+    assert_eq!(p_evals.zip_map(&q_evals, |a, b| a + b), &p_evals + &q_evals);
+}
+
+#[test]
+#[should_panic(expected = "domains are unequal")]
+fn evaluations_zip_map_domain_mismatch() {
+    let rng = &mut TestRng::default();
+    let p = DensePolynomial::<Fr>::rand(5, rng);
+    let small = p.clone().evaluate_over_domain(EvaluationDomain::<Fr>::new(8).unwrap());
+    let large = p.evaluate_over_domain(EvaluationDomain::<Fr>::new(16).unwrap());
+    let _ = small.zip_map(&large, |a, b| a + b);
+}
+
+#[test]
+fn evaluations_try_zip_map() {
+    let rng = &mut TestRng::default();
+    let p = DensePolynomial::<Fr>::rand(5, rng);
+    let small = p.clone().evaluate_over_domain(EvaluationDomain::<Fr>::new(8).unwrap());
+    let large = p.evaluate_over_domain(EvaluationDomain::<Fr>::new(16).unwrap());
+    assert_eq!(small.try_zip_map(&small, |a, b| a + b), Ok(small.zip_map(&small, |a, b| a + b)));
+    assert_eq!(small.try_zip_map(&large, |a, b| a + b), Err(DomainError::DomainMismatch { expected: 8, found: 16 }));
+}
+
+#[test]
+fn evaluations_rotate() {
+    let rng = &mut TestRng::default();
+    let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+    let p = DensePolynomial::<Fr>::rand(10, rng);
+    let evaluations = p.clone().evaluate_over_domain(domain);
+    for k in [-33isize, -16, -1, 0, 1, 5, 16, 17] {
+        // Rotating by `k` evaluates `p(g^k * X)`.
+        let shift = if k >= 0 { domain.group_gen.pow([k as u64]) } else { domain.group_gen_inv.pow([(-k) as u64]) };
+        let shifted = evaluations.rotate(k);
+        assert_eq!(shifted.evaluations, domain.elements().map(|x| p.evaluate(shift * x)).collect::<Vec<_>>());
+        assert_eq!(shifted.rotate(-k), evaluations);
+    }
+}
+
+#[test]
+fn evaluations_fold_in_half() {
+    let rng = &mut TestRng::default();
+    let domain = EvaluationDomain::<Fr>::new(32).unwrap();
+    for degree in [0, 1, 7, 15, 31] {
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        let r = Fr::rand(rng);
+        let folded = p.clone().evaluate_over_domain(domain).fold_in_half(r);
+        assert_eq!(folded.domain(), domain.halve().unwrap());
+
+        // Folding a polynomial of degree `d` yields `p_even + r * p_odd`, of degree at most `d / 2`.
+        let even = p.coeffs.iter().step_by(2).copied().collect::<Vec<_>>();
+        let odd = p.coeffs.iter().skip(1).step_by(2).map(|c| r * c).collect::<Vec<_>>();
+        let expected = &DensePolynomial::from_coefficients_vec(even) + &DensePolynomial::from_coefficients_vec(odd);
+        let interpolated = folded.interpolate();
+        assert!(interpolated.degree() <= degree / 2);
+        assert_eq!(interpolated, expected);
+    }
+}
+
+#[test]
+fn evaluations_interpolate_bounded() {
+    let rng = &mut TestRng::default();
+    let domain = EvaluationDomain::<Fr>::new(32).unwrap();
+    for degree in [0, 1, 10, 31] {
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        let evaluations = p.clone().evaluate_over_domain(domain);
+        for degree_bound in [degree, degree + 1, 100] {
+            assert_eq!(evaluations.clone().interpolate_bounded(degree_bound), p);
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "The interpolated polynomial has degree greater than 4")]
+fn evaluations_interpolate_bounded_too_small() {
+    let rng = &mut TestRng::default();
+    let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+    let _ = DensePolynomial::<Fr>::rand(10, rng).evaluate_over_domain(domain).interpolate_bounded(4);
+}
@@ -18,8 +18,15 @@ pub mod standard;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
-use snarkvm_fields::PrimeField;
+use snarkvm_curves::{
+    bls12_377::G1Affine,
+    traits::{AffineCurve, ProjectiveCurve},
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter_mut, BigInteger};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 use core::any::TypeId;
 
@@ -67,11 +74,96 @@ impl VariableBase {
     }
 }
 
+/// A set of bases that is shared by many MSMs, together with the multiples of each base by `2^(window * j)`
+/// for each window `j`. This turns every subsequent MSM into a single bucket accumulation over all windows,
+/// which skips the per-window bucket reductions and doublings of [`VariableBase::msm`].
+///
+/// The table holds `ceil(num_bits / window)` points per base, so this trades memory for speed,
+/// and is only worthwhile when the same bases are used for several MSMs.
+#[derive(Clone, Debug)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The multiple `2^(window * j) * bases[i]` is stored at location `j * num_bases + i`.
+    table: Vec<G>,
+    num_bases: usize,
+    window: usize,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Precompute the window table for `bases`, with a window size chosen as in [`VariableBase::msm`].
+    pub fn new(bases: &[G]) -> Self {
+        let window = match bases.len() < 32 {
+            true => 1,
+            false => super::ln_without_floats(bases.len()) + 2,
+        };
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = num_bits / window + usize::from(num_bits % window != 0);
+        assert!(num_windows * bases.len() <= u32::MAX as usize, "Too many bases to precompute");
+
+        let mut current = bases.iter().map(|b| b.to_projective()).collect::<Vec<_>>();
+        let mut table = Vec::with_capacity(num_windows * bases.len());
+        for _ in 0..num_windows {
+            let mut next = current.clone();
+            cfg_iter_mut!(next).for_each(|p| (0..window).for_each(|_| p.double_in_place()));
+            table.extend(G::Projective::batch_normalization_into_affine(core::mem::replace(&mut current, next)));
+        }
+        Self { table, num_bases: bases.len(), window }
+    }
+
+    /// Returns the number of bases.
+    pub fn num_bases(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Compute `sum_i scalars[i] * bases[i]`, as [`VariableBase::msm`] would.
+    /// Panics if there are more scalars than bases.
+    pub fn msm(&self, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        assert!(scalars.len() <= self.num_bases, "There are more scalars than bases");
+        // Without scalars, and in particular without bases, there is nothing to sum.
+        if scalars.is_empty() {
+            return G::Projective::zero();
+        }
+        let num_windows = self.table.len() / self.num_bases;
+        let mask = (1u64 << self.window) - 1;
+
+        // Place the `j`-th window of each scalar into the bucket for its digit, skipping zero digits.
+        let mut bucket_positions = Vec::with_capacity(num_windows * scalars.len());
+        for (i, scalar) in scalars.iter().enumerate() {
+            let mut scalar = *scalar;
+            for j in 0..num_windows {
+                let digit = scalar.as_ref()[0] & mask;
+                if digit != 0 {
+                    let scalar_index = (j * self.num_bases + i) as u32;
+                    bucket_positions.push(batched::BucketPosition { bucket_index: digit as u32 - 1, scalar_index });
+                }
+                scalar.divn(self.window as u32);
+            }
+        }
+        if bucket_positions.is_empty() {
+            return G::Projective::zero();
+        }
+
+        // Sum the buckets, weighting the bucket for digit `d` by `d`.
+        let buckets = batched::batch_add((1 << self.window) - 1, &self.table, &mut bucket_positions);
+        let mut result = G::Projective::zero();
+        let mut running_sum = G::Projective::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum.add_assign_mixed(&bucket);
+            result += &running_sum;
+        }
+        result
+    }
+
+    /// Compute [`Self::msm`] for each vector of scalars in `scalars`, in parallel.
+    pub fn batch_msm(&self, scalars: &[Vec<<G::ScalarField as PrimeField>::BigInteger>]) -> Vec<G::Projective> {
+        cfg_into_iter!(0..scalars.len()).map(|i| self.msm(&scalars[i])).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use snarkvm_curves::bls12_377::{Fr, G1Affine};
-    use snarkvm_fields::PrimeField;
+    use snarkvm_fields::{PrimeField, Zero};
     use snarkvm_utilities::rand::TestRng;
 
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -102,7 +194,18 @@ mod tests {
 
             let candidate = batched::msm(bases.as_slice(), scalars.as_slice()).to_affine();
             assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
+
+            let precomputed = PrecomputedBases::new(&bases);
+            assert_eq!(naive_a, precomputed.msm(&scalars).to_affine(), "MSM size: {msm_size}");
+            // Fewer scalars than bases use a prefix of the bases.
+            let prefix = VariableBase::msm_naive(&bases[..msm_size / 2], &scalars[..msm_size / 2]).to_affine();
+            assert_eq!(prefix, precomputed.msm(&scalars[..msm_size / 2]).to_affine(), "MSM size: {msm_size}");
         }
+
+        // Without bases, every MSM is of no scalars.
+        let precomputed = PrecomputedBases::<G1Affine>::new(&[]);
+        assert!(precomputed.msm(&[]).is_zero());
+        assert!(precomputed.batch_msm(&[vec![], vec![]]).iter().all(|c| c.is_zero()));
     }
 
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
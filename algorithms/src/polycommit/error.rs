@@ -12,11 +12,85 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+/// The error type for operations on polynomials and domains in this module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolyCommitError {
+    /// An evaluation domain of the given size is not supported by the field.
+    UnsupportedDomainSize(usize),
+
+    /// The polynomial labelled `label` has a degree greater than its degree bound.
+    DegreeBoundExceeded {
+        /// The label of the polynomial.
+        label: String,
+        /// Degree of the polynomial.
+        degree: usize,
+        /// Degree bound.
+        degree_bound: usize,
+    },
+
+    /// The size of a domain does not match the expected size.
+    DomainMismatch {
+        /// The expected size of the domain.
+        expected: usize,
+        /// The actual size of the domain.
+        found: usize,
+    },
+
+    /// An inversion required by the operation was of zero.
+    ZeroInInversion,
+
+    /// The polynomial labelled `label` has a degree that is not less than the stride used to pack it.
+    StrideExceeded {
+        /// The label of the polynomial.
+        label: String,
+        /// Degree of the polynomial.
+        degree: usize,
+        /// The stride between the packed polynomials.
+        stride: usize,
+    },
+
+    /// Packing `count` polynomials with the given `stride` needs more than `usize::MAX` coefficients.
+    PackedLengthOverflow {
+        /// The number of polynomials to pack.
+        count: usize,
+        /// The stride between the packed polynomials.
+        stride: usize,
+    },
+}
+
+impl snarkvm_utilities::error::Error for PolyCommitError {}
+
+impl core::fmt::Display for PolyCommitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedDomainSize(size) => write!(f, "an evaluation domain of size {size} is not supported"),
+            Self::DegreeBoundExceeded { label, degree, degree_bound } => write!(
+                f,
+                "the polynomial \"{label}\" has degree {degree}, which exceeds its degree bound ({degree_bound})"
+            ),
+            Self::DomainMismatch { expected, found } => {
+                write!(f, "expected a domain of size {expected}, but found one of size {found}")
+            }
+            Self::ZeroInInversion => write!(f, "cannot invert zero"),
+            Self::StrideExceeded { label, degree, stride } => write!(
+                f,
+                "the polynomial \"{label}\" has degree {degree}, which does not fit within the stride ({stride})"
+            ),
+            Self::PackedLengthOverflow { count, stride } => {
+                write!(f, "packing {count} polynomials with stride {stride} overflows the number of coefficients")
+            }
+        }
+    }
+}
+
 /// The error type for `PolynomialCommitment`.
 #[derive(Debug)]
 pub enum PCError {
     AnyhowError(anyhow::Error),
 
+    /// An operation on polynomials or domains failed.
+    PolyCommitError(PolyCommitError),
+
     /// The query set contains a label for a polynomial that was not provided as
     /// input to the `PC::open`.
     MissingPolynomial {
@@ -31,6 +105,12 @@ pub enum PCError {
         label: String,
     },
 
+    /// A polynomial labelled `label` was provided more than once.
+    DuplicatePolynomial {
+        /// The label of the duplicated polynomial.
+        label: String,
+    },
+
     /// The provided polynomial was meant to be hiding, but `rng` was `None`.
     MissingRng,
 
@@ -71,6 +151,21 @@ pub enum PCError {
     /// had a strict degree bound.
     EquationHasDegreeBounds(String),
 
+    /// The batch check of evaluation proofs failed.
+    BatchCheckFailed {
+        /// The indices of the openings found to be invalid, if they were identified.
+        failed_openings: Vec<usize>,
+    },
+
+    /// The polynomial with the given label is not in canonical form.
+    NonCanonicalPolynomial {
+        /// The label of the polynomial.
+        label: String,
+    },
+
+    /// The linear combination with the given label has no terms.
+    EmptyLinearCombination(String),
+
     /// The required degree bound is not supported by ck/vk
     UnsupportedDegreeBound(usize),
 
@@ -92,6 +187,22 @@ pub enum PCError {
         label: String,
     },
 
+    /// The polynomial has too large a degree to be represented by its evaluations over a domain.
+    PolynomialDegreeTooLargeForDomain {
+        /// The label of the polynomial.
+        label: String,
+        /// Degree of the polynomial.
+        poly_degree: usize,
+        /// Size of the domain.
+        domain_size: usize,
+    },
+
+    /// No committer keys were provided to select from.
+    NoCommitterKeys,
+
+    /// None of the committer keys supports the polynomials. Contains the reason why the largest key does not.
+    NoSufficientCommitterKey(Box<PCError>),
+
     Terminated,
 }
 
@@ -103,10 +214,17 @@ impl From<anyhow::Error> for PCError {
     }
 }
 
+impl From<PolyCommitError> for PCError {
+    fn from(other: PolyCommitError) -> Self {
+        Self::PolyCommitError(other)
+    }
+}
+
 impl core::fmt::Display for PCError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::AnyhowError(error) => write!(f, "{error}"),
+            Self::PolyCommitError(error) => write!(f, "{error}"),
             Self::MissingPolynomial { label } => {
                 write!(f, "`QuerySet` refers to polynomial \"{label}\", but it was not provided.")
             }
@@ -114,6 +232,7 @@ impl core::fmt::Display for PCError {
                 f,
                 "`QuerySet` refers to polynomial \"{label}\", but `Evaluations` does not contain an evaluation for it."
             ),
+            Self::DuplicatePolynomial { label } => write!(f, "the polynomial \"{label}\" was provided more than once"),
             Self::MissingRng => write!(f, "hiding commitments require `Some(rng)`"),
             Self::DegreeIsZero => write!(f, "this scheme does not support committing to degree 0 polynomials"),
             Self::TooManyCoefficients { num_coefficients, num_powers } => write!(
@@ -130,6 +249,15 @@ impl core::fmt::Display for PCError {
             Self::EquationHasDegreeBounds(e) => {
                 write!(f, "the eqaution \"{e}\" contained degree-bounded polynomials")
             }
+            Self::BatchCheckFailed { failed_openings } => {
+                write!(f, "the batch check of evaluation proofs failed (invalid openings: {failed_openings:?})")
+            }
+            Self::NonCanonicalPolynomial { label } => {
+                write!(f, "the polynomial \"{label}\" is not in canonical form")
+            }
+            Self::EmptyLinearCombination(label) => {
+                write!(f, "the linear combination \"{label}\" has no terms")
+            }
             Self::UnsupportedDegreeBound(bound) => {
                 write!(f, "the degree bound ({bound:?}) is not supported by the parameters")
             }
@@ -147,6 +275,14 @@ impl core::fmt::Display for PCError {
                 "the degree bound ({degree_bound}) for the polynomial {label} \
                  (having degree {poly_degree}) is greater than the maximum degree ({max_degree})"
             ),
+            Self::PolynomialDegreeTooLargeForDomain { label, poly_degree, domain_size } => write!(
+                f,
+                "the polynomial {label} (having degree {poly_degree}) cannot be evaluated over a domain of size {domain_size}"
+            ),
+            Self::NoCommitterKeys => write!(f, "no committer keys were provided"),
+            Self::NoSufficientCommitterKey(reason) => {
+                write!(f, "no committer key supports the polynomials; the largest key fails with: {reason}")
+            }
             Self::Terminated => write!(f, "terminated"),
         }
     }
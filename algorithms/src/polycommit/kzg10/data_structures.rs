@@ -14,10 +14,12 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::VariableBase,
+    polycommit::PCError,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, ToConstraintField, Zero};
+use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField, Zero};
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{
     borrow::Cow,
@@ -26,12 +28,13 @@ use snarkvm_utilities::{
     serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate},
     FromBytes,
     ToBytes,
+    BigInteger,
     ToMinimalBits,
 };
 
 use crate::srs::{UniversalProver, UniversalVerifier};
 use anyhow::Result;
-use core::ops::{Add, AddAssign};
+use core::ops::{Add, AddAssign, Mul};
 use parking_lot::RwLock;
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
@@ -300,6 +303,18 @@ impl<E: PairingEngine> KZGCommitment<E> {
         KZGCommitment(E::G1Affine::zero())
     }
 
+    /// Returns the commitment to the zero polynomial, which is the identity of `E::G1Affine`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::empty()
+    }
+
+    /// Returns `true` if `self` is the identity, which is the commitment to the zero polynomial without hiding.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
     pub fn has_degree_bound(&self) -> bool {
         false
     }
@@ -307,6 +322,35 @@ impl<E: PairingEngine> KZGCommitment<E> {
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
         self.0.is_in_correct_subgroup_assuming_on_curve()
     }
+
+    /// Precompute the multiples of `self` that are needed to scale it, for verifiers that use it repeatedly.
+    pub fn prepare(&self) -> KZGPreparedCommitment<E> {
+        KZGPreparedCommitment::new(*self)
+    }
+
+    /// Update `self`, a commitment to `p(X)` under `powers`, into a commitment to
+    /// `p(X) + sum_i new_coeffs[i] * X^(start_degree + i)`.
+    /// Since commitments are additive, this only requires an MSM of size `new_coeffs.len()`.
+    ///
+    /// This updates the (unshifted) commitment only; hiding is unaffected,
+    /// but commitments that enforce a degree bound must be recomputed.
+    pub fn update_append(
+        &mut self,
+        new_coeffs: &[E::Fr],
+        start_degree: usize,
+        powers: &Powers<E>,
+    ) -> Result<(), PCError> {
+        let num_powers = powers.size();
+        let end = start_degree.saturating_add(new_coeffs.len());
+        if end > num_powers {
+            return Err(PCError::TooManyCoefficients { num_coefficients: end, num_powers });
+        }
+        let scalars = new_coeffs.iter().map(|c| c.to_bigint()).collect::<Vec<_>>();
+        let mut commitment = self.0.to_projective();
+        commitment += VariableBase::msm(&powers.powers_of_beta_g[start_degree..end], &scalars);
+        self.0 = commitment.to_affine();
+        Ok(())
+    }
 }
 
 impl<E: PairingEngine> ToConstraintField<E::Fq> for KZGCommitment<E> {
@@ -315,6 +359,126 @@ impl<E: PairingEngine> ToConstraintField<E::Fq> for KZGCommitment<E> {
     }
 }
 
+/// `KZGPreparedCommitment` is a commitment together with its multiples by `2^i` for every bit `i` of a scalar,
+/// so that scaling it takes one mixed addition per set bit of the scalar, and no doublings.
+///
+/// This is only worthwhile for commitments that are checked repeatedly, such as commitments to the circuit
+/// that are reused across many proofs. Only the commitment itself is serialized.
+#[derive(Clone, Debug)]
+pub struct KZGPreparedCommitment<E: PairingEngine> {
+    commitment: KZGCommitment<E>,
+    /// The multiple `2^i * commitment` is stored at index `i`.
+    powers_of_two: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> KZGPreparedCommitment<E> {
+    /// Precompute the multiples of `commitment` by powers of two.
+    pub fn new(commitment: KZGCommitment<E>) -> Self {
+        let num_bits = <E::Fr as PrimeField>::size_in_bits();
+        let mut current = commitment.0.to_projective();
+        let mut powers_of_two = Vec::with_capacity(num_bits);
+        for _ in 0..num_bits {
+            powers_of_two.push(current);
+            current.double_in_place();
+        }
+        Self { commitment, powers_of_two: E::G1Projective::batch_normalization_into_affine(powers_of_two) }
+    }
+
+    /// Returns the commitment that `self` was prepared from.
+    pub fn commitment(&self) -> &KZGCommitment<E> {
+        &self.commitment
+    }
+}
+
+impl<E: PairingEngine> PartialEq for KZGPreparedCommitment<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.commitment == other.commitment
+    }
+}
+
+impl<E: PairingEngine> Eq for KZGPreparedCommitment<E> {}
+
+impl<E: PairingEngine> From<KZGCommitment<E>> for KZGPreparedCommitment<E> {
+    fn from(commitment: KZGCommitment<E>) -> Self {
+        Self::new(commitment)
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for KZGPreparedCommitment<E> {
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.commitment.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.commitment.serialized_size(compress)
+    }
+}
+
+impl<E: PairingEngine> Valid for KZGPreparedCommitment<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.commitment.check()
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for KZGPreparedCommitment<E> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self::new(KZGCommitment::deserialize_with_mode(reader, compress, validate)?))
+    }
+}
+
+/// A commitment in a form that verifiers can scale and combine,
+/// which is either a [`KZGCommitment`] or a [`KZGPreparedCommitment`].
+pub trait VerifierCommitment<E: PairingEngine>: CanonicalSerialize + Sync + 'static {
+    /// Returns the commitment itself.
+    fn commitment(&self) -> &KZGCommitment<E>;
+
+    /// Returns `scalar * self`.
+    fn scale(&self, scalar: E::Fr) -> E::G1Projective;
+
+    /// Returns `sum_i coeffs[i] * commitments[i]`.
+    fn linear_combination<'a>(coeffs_and_comms: impl IntoIterator<Item = (E::Fr, &'a Self)>) -> E::G1Projective {
+        coeffs_and_comms.into_iter().map(|(coeff, comm)| comm.scale(coeff)).sum()
+    }
+}
+
+impl<E: PairingEngine> VerifierCommitment<E> for KZGCommitment<E> {
+    fn commitment(&self) -> &KZGCommitment<E> {
+        self
+    }
+
+    fn scale(&self, scalar: E::Fr) -> E::G1Projective {
+        self.0.mul(scalar)
+    }
+
+    /// Combines the commitments with a single MSM.
+    fn linear_combination<'a>(coeffs_and_comms: impl IntoIterator<Item = (E::Fr, &'a Self)>) -> E::G1Projective {
+        let (scalars, bases): (Vec<_>, Vec<_>) =
+            coeffs_and_comms.into_iter().map(|(f, c)| (f.to_bigint(), c.0)).unzip();
+        VariableBase::msm(&bases, &scalars)
+    }
+}
+
+impl<E: PairingEngine> VerifierCommitment<E> for KZGPreparedCommitment<E> {
+    fn commitment(&self) -> &KZGCommitment<E> {
+        &self.commitment
+    }
+
+    fn scale(&self, scalar: E::Fr) -> E::G1Projective {
+        let scalar = scalar.to_bigint();
+        let mut result = E::G1Projective::zero();
+        for (i, power_of_two) in self.powers_of_two.iter().enumerate() {
+            if scalar.get_bit(i) {
+                result.add_assign_mixed(power_of_two);
+            }
+        }
+        result
+    }
+}
+
 /// `KZGRandomness` hides the polynomial inside a commitment. It is output by `KZG10::commit`.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct KZGRandomness<E: PairingEngine> {
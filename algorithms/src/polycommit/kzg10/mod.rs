@@ -449,16 +449,10 @@ impl<E: PairingEngine> KZG10<E> {
 }
 
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<F>) -> (usize, Vec<F::BigInteger>) {
-    if p.coeffs.is_empty() {
-        (0, vec![])
-    } else {
-        let mut num_leading_zeros = 0;
-        while p.coeffs[num_leading_zeros].is_zero() && num_leading_zeros < p.coeffs.len() {
-            num_leading_zeros += 1;
-        }
-        let coeffs = convert_to_bigints(&p.coeffs[num_leading_zeros..]);
-        (num_leading_zeros, coeffs)
-    }
+    // The coefficients may all be zero, e.g. for a zero polynomial that was padded to a larger degree.
+    let num_leading_zeros = p.coeffs.iter().take_while(|c| c.is_zero()).count();
+    let coeffs = convert_to_bigints(&p.coeffs[num_leading_zeros..]);
+    (num_leading_zeros, coeffs)
 }
 
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
@@ -637,4 +631,28 @@ mod tests {
         assert!(p.degree() > max_degree);
         assert!(KZG_Bls12_377::check_degree_is_too_large(p.degree(), powers.size()).is_err());
     }
+
+    #[test]
+    fn test_commitment_update_append() {
+        let rng = &mut TestRng::default();
+
+        let pp = KZG_Bls12_377::load_srs(32).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, 32, None);
+        let commit = |p: &DensePolynomial<Fr>| KZG_Bls12_377::commit(&powers, &p.into(), None, None).unwrap().0;
+
+        let mut p = DensePolynomial::<Fr>::rand(10, rng);
+        let mut commitment = commit(&p);
+        for num_new_coeffs in [1, 5, 0, 10] {
+            let new_coeffs = (0..num_new_coeffs).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            commitment.update_append(&new_coeffs, p.coeffs.len(), &powers).unwrap();
+            p.coeffs.extend(new_coeffs);
+            assert_eq!(commitment, commit(&p));
+        }
+
+        // Appending beyond the powers fails.
+        assert!(matches!(
+            commitment.update_append(&[Fr::rand(rng)], powers.size(), &powers),
+            Err(PCError::TooManyCoefficients { .. })
+        ));
+    }
 }
@@ -13,8 +13,13 @@
 // limitations under the License.
 
 use super::{LabeledPolynomial, PolynomialInfo};
-use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
-use snarkvm_curves::PairingEngine;
+use crate::{
+    crypto_hash::sha256::sha256,
+    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    msm::{PrecomputedBases, VariableBase},
+    polycommit::{kzg10, PCError, PolyCommitError},
+};
+use snarkvm_curves::{PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
@@ -24,6 +29,7 @@ use std::{
     collections::{BTreeMap, BTreeSet},
     fmt,
     ops::{AddAssign, MulAssign, SubAssign},
+    sync::Arc,
 };
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
@@ -35,6 +41,19 @@ pub type Randomness<E> = kzg10::KZGRandomness<E>;
 /// `Commitment` is the commitment for the KZG10 scheme.
 pub type Commitment<E> = kzg10::KZGCommitment<E>;
 
+/// `PreparedCommitment` is the prepared commitment for the KZG10 scheme.
+pub type PreparedCommitment<E> = kzg10::KZGPreparedCommitment<E>;
+
+/// `CommitMetrics` records the cost of committing to a single labeled polynomial.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommitMetrics {
+    /// The time taken to commit to the polynomial.
+    pub duration: std::time::Duration,
+    /// The total number of bases in the MSMs used to compute the commitment.
+    pub msm_size: usize,
+}
+
 /// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
 #[derive(Clone, Debug, Default, Hash, CanonicalSerialize, CanonicalDeserialize, PartialEq, Eq)]
 pub struct CommitterKey<E: PairingEngine> {
@@ -283,6 +302,124 @@ impl<E: PairingEngine> CommitterKey<E> {
     fn len(&self) -> usize {
         if self.shifted_powers_of_beta_g.is_some() { self.shifted_powers_of_beta_g.as_ref().unwrap().len() } else { 0 }
     }
+
+    /// Commit to each of `polynomials`, which must be non-hiding and have no degree bound.
+    ///
+    /// The multiples of the powers in `self` that are needed by the MSMs are precomputed once
+    /// (see [`PrecomputedBases`]), and are then shared by the MSMs for all of the polynomials,
+    /// which run in parallel. The commitments are identical to those produced by [`super::SonicKZG10::commit`].
+    /// Each shared MSM is about 20% cheaper than [`VariableBase::msm`], but the precomputation costs about as much
+    /// as a dozen MSMs over the largest polynomial, so this only pays off when committing to many polynomials.
+    ///
+    /// Returns `PCError::MissingRng` for a hiding polynomial, `PCError::UnsupportedDegreeBound` for a
+    /// polynomial with a degree bound, and `PCError::TooManyCoefficients` if a polynomial is too large for `self`.
+    pub fn commit_batch(&self, polynomials: &[LabeledPolynomial<E::Fr>]) -> Result<Vec<Commitment<E>>, PCError> {
+        let num_powers = self.powers_of_beta_g.len();
+        let scalars = polynomials
+            .iter()
+            .map(|p| {
+                if p.is_hiding() {
+                    return Err(PCError::MissingRng);
+                }
+                if let Some(degree_bound) = p.degree_bound() {
+                    return Err(PCError::UnsupportedDegreeBound(degree_bound));
+                }
+                let coeffs = p.polynomial().to_dense();
+                if coeffs.len() > num_powers {
+                    return Err(PCError::TooManyCoefficients { num_coefficients: coeffs.len(), num_powers });
+                }
+                Ok(coeffs.iter().map(|c| c.to_bigint()).collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num_bases = scalars.iter().map(Vec::len).max().unwrap_or(0);
+        let bases = PrecomputedBases::new(&self.powers_of_beta_g[..num_bases]);
+        Ok(bases.batch_msm(&scalars).into_iter().map(|c| kzg10::KZGCommitment(c.to_affine())).collect())
+    }
+
+    /// Commit to the polynomial whose evaluations over their domain are `evaluations`, without hiding.
+    ///
+    /// The commitment is a single MSM of `evaluations` with the Lagrange basis in `self` for the domain,
+    /// so a prover holding evaluations does not need an IFFT of size `n` (that is, `O(n log n)` field
+    /// operations) to obtain coefficients first. The Lagrange basis for each supported domain size is
+    /// computed once, in `trim`, by an IFFT over `n` group elements, which costs about `n log n` group operations.
+    /// The commitment is identical to a commitment to the interpolated polynomial.
+    ///
+    /// Returns `PCError::UnsupportedLagrangeBasisSize` if `self` has no Lagrange basis for the domain.
+    pub fn lagrange_commitment(&self, evaluations: &EvaluationsOnDomain<E::Fr>) -> Result<Commitment<E>, PCError> {
+        let size = evaluations.domain().size();
+        let basis = self.lagrange_bases_at_beta_g.get(&size).ok_or(PCError::UnsupportedLagrangeBasisSize(size))?;
+        if evaluations.evaluations.len() != size {
+            return Err(PolyCommitError::DomainMismatch { expected: size, found: evaluations.evaluations.len() }.into());
+        }
+        let scalars = evaluations.evaluations.iter().map(|e| e.to_bigint()).collect::<Vec<_>>();
+        Ok(kzg10::KZGCommitment(VariableBase::msm(basis, &scalars).to_affine()))
+    }
+
+    /// Update `commitment`, a commitment to `p(X)` under `self`, into a commitment to
+    /// `p(X) + sum_i new_coeffs[i] * X^(start_degree + i)`, at the cost of an MSM of size `new_coeffs.len()`.
+    /// See [`kzg10::KZGCommitment::update_append`], which this calls with the powers in `self`.
+    pub fn update_append(
+        &self,
+        commitment: &mut Commitment<E>,
+        new_coeffs: &[E::Fr],
+        start_degree: usize,
+    ) -> Result<(), PCError> {
+        let powers = kzg10::Powers {
+            powers_of_beta_g: self.powers_of_beta_g.as_slice().into(),
+            powers_of_beta_times_gamma_g: self.powers_of_beta_times_gamma_g.as_slice().into(),
+        };
+        commitment.update_append(new_coeffs, start_degree, &powers)
+    }
+
+    /// Returns the maximum degree of a polynomial that can be committed to with `self`.
+    pub fn supported_degree(&self) -> usize {
+        self.powers_of_beta_g.len().saturating_sub(1)
+    }
+
+    /// Check that `self` can commit to each of `polynomials`, including their hiding bounds
+    /// and the shifts required by their degree bounds.
+    ///
+    /// Returns the same errors as [`super::SonicKZG10::commit`] would for the first unsupported polynomial.
+    pub fn check_supports(&self, polynomials: &[LabeledPolynomial<E::Fr>]) -> Result<(), PCError> {
+        let num_powers = self.powers_of_beta_g.len();
+        for p in polynomials {
+            let num_coefficients = p.degree() + 1;
+            if num_coefficients > num_powers {
+                return Err(PCError::TooManyCoefficients { num_coefficients, num_powers });
+            }
+            if let Some(hiding_bound) = p.hiding_bound() {
+                kzg10::KZG10::<E>::check_hiding_bound(hiding_bound, self.powers_of_beta_times_gamma_g.len())?;
+            }
+            kzg10::KZG10::<E>::check_degrees_and_bounds(
+                self.supported_degree(),
+                self.enforced_degree_bounds.as_deref(),
+                p,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the key of smallest supported degree among `keys` that can commit to all of `polynomials`,
+/// as checked by [`CommitterKey::check_supports`].
+///
+/// Returns `PCError::NoCommitterKeys` if `keys` is empty, and `PCError::NoSufficientCommitterKey`,
+/// with the reason why the largest key does not suffice, if no key is large enough.
+pub fn select_key<'k, E: PairingEngine>(
+    keys: &'k [CommitterKey<E>],
+    polynomials: &[LabeledPolynomial<E::Fr>],
+) -> Result<&'k CommitterKey<E>, PCError> {
+    let selected =
+        keys.iter().filter(|key| key.check_supports(polynomials).is_ok()).min_by_key(|key| key.supported_degree());
+    match selected {
+        Some(key) => Ok(key),
+        None => {
+            let largest = keys.iter().max_by_key(|key| key.supported_degree()).ok_or(PCError::NoCommitterKeys)?;
+            let reason = largest.check_supports(polynomials).expect_err("no key supports the polynomials");
+            Err(PCError::NoSufficientCommitterKey(Box::new(reason)))
+        }
+    }
 }
 
 /// `CommitterUnionKey` is a union of `CommitterKey`s, useful for multi-circuit batch proofs.
@@ -411,7 +548,259 @@ impl<E: PairingEngine> BatchProof<E> {
 }
 
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
-pub type PolynomialLabel = String;
+///
+/// Labels constructed with [`PolynomialLabel::new`] or `From` are interned, so labels with the same string
+/// share a single allocation, and cloning or comparing them for equality does not touch the string.
+/// At most [`MAX_INTERNED_LABELS`] distinct strings are interned at a time; the storage of a string is freed
+/// once no label refers to it. Deserialized labels are never interned, as their strings are not trusted.
+/// Labels are ordered and hashed as their strings are, so maps keyed by label iterate in the same order
+/// as if they were keyed by `String`, and can be queried with a `&str`. Labels are serialized as their strings.
+#[derive(Clone)]
+pub struct PolynomialLabel(Arc<str>);
+
+/// The maximum number of distinct strings that are interned at a time.
+pub const MAX_INTERNED_LABELS: usize = 1 << 16;
+
+/// The interned strings of labels.
+pub(super) static LABELS: parking_lot::RwLock<BTreeSet<Arc<str>>> = parking_lot::const_rwlock(BTreeSet::new());
+
+impl PolynomialLabel {
+    /// Returns the interned label for `label`, allocating it if no label with the same string exists yet.
+    ///
+    /// If [`MAX_INTERNED_LABELS`] strings that are still in use are interned, the label is not interned.
+    pub fn new(label: &str) -> Self {
+        if let Some(interned) = LABELS.read().get(label) {
+            return Self(interned.clone());
+        }
+        // Another thread may have interned `label` since the read lock was released, so check again.
+        let mut labels = LABELS.write();
+        if let Some(interned) = labels.get(label) {
+            return Self(interned.clone());
+        }
+        if labels.len() >= MAX_INTERNED_LABELS {
+            // Free the strings that are only referenced by the interner.
+            labels.retain(|interned| Arc::strong_count(interned) > 1);
+        }
+        let interned: Arc<str> = label.into();
+        if labels.len() < MAX_INTERNED_LABELS {
+            labels.insert(interned.clone());
+        }
+        Self(interned)
+    }
+
+    /// Returns a label for `label` that does not share its storage with any other label.
+    fn new_uninterned(label: String) -> Self {
+        Self(label.into())
+    }
+
+    /// Returns the string of `self`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if `self` and `other` share their storage, which implies that they are equal.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl From<&str> for PolynomialLabel {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<&String> for PolynomialLabel {
+    fn from(label: &String) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for PolynomialLabel {
+    fn from(label: String) -> Self {
+        Self::new(&label)
+    }
+}
+
+impl From<PolynomialLabel> for String {
+    fn from(label: PolynomialLabel) -> Self {
+        label.as_str().to_string()
+    }
+}
+
+impl core::ops::Deref for PolynomialLabel {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for PolynomialLabel {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for PolynomialLabel {
+    #[inline]
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for PolynomialLabel {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.0 == other.0
+    }
+}
+
+impl Eq for PolynomialLabel {}
+
+impl PartialEq<str> for PolynomialLabel {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PolynomialLabel {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for PolynomialLabel {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<PolynomialLabel> for str {
+    fn eq(&self, other: &PolynomialLabel) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<PolynomialLabel> for &str {
+    fn eq(&self, other: &PolynomialLabel) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<PolynomialLabel> for String {
+    fn eq(&self, other: &PolynomialLabel) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl Ord for PolynomialLabel {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        if self.ptr_eq(other) { core::cmp::Ordering::Equal } else { self.0.cmp(&other.0) }
+    }
+}
+
+impl PartialOrd for PolynomialLabel {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for PolynomialLabel {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // Hash the string, as `Borrow<str>` requires.
+        self.0.hash(state)
+    }
+}
+
+impl fmt::Debug for PolynomialLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for PolynomialLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PolynomialLabel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PolynomialLabel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::new_uninterned(String::deserialize(deserializer)?))
+    }
+}
+
+impl CanonicalSerialize for PolynomialLabel {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.as_str().to_string().serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        self.0.len() + 8
+    }
+}
+
+impl Valid for PolynomialLabel {
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl CanonicalDeserialize for PolynomialLabel {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self::new_uninterned(String::deserialize_with_mode(reader, compress, validate)?))
+    }
+}
+
+/// Returns the label for the `i`-th polynomial named `prefix`, which is `prefix` followed by `_` and `i`
+/// padded with zeros to 8 digits, e.g. `w_00000003`. The padding makes the lexicographic order of the labels,
+/// and hence the order of maps keyed by label, agree with the order of the indices below `10^8`.
+pub fn indexed(prefix: &str, i: usize) -> PolynomialLabel {
+    format!("{prefix}_{i:0>8}").into()
+}
+
+/// Generates labels of the form [`indexed`]`(prefix, i)`, with a separate counter `i` for each prefix,
+/// so that provers and verifiers that generate labels in the same order agree on them.
+#[derive(Clone, Debug, Default)]
+pub struct LabelGenerator {
+    counters: BTreeMap<String, usize>,
+}
+
+impl LabelGenerator {
+    /// Construct a generator that starts every prefix at index `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the next label for `prefix`. Each label is returned at most once by `self`.
+    pub fn next(&mut self, prefix: &str) -> PolynomialLabel {
+        let counter = self.counters.entry(prefix.to_string()).or_default();
+        let label = indexed(prefix, *counter);
+        *counter += 1;
+        label
+    }
+}
 
 /// A commitment along with information about its degree bound (if any).
 #[derive(Clone, Debug, CanonicalSerialize, PartialEq, Eq)]
@@ -440,12 +829,12 @@ impl<C: CanonicalSerialize + ToBytes> ToBytes for LabeledCommitment<C> {
 
 impl<C: CanonicalSerialize> LabeledCommitment<C> {
     /// Instantiate a new polynomial_context.
-    pub fn new(label: PolynomialLabel, commitment: C, degree_bound: Option<usize>) -> Self {
-        Self { label, commitment, degree_bound }
+    pub fn new(label: impl Into<PolynomialLabel>, commitment: C, degree_bound: Option<usize>) -> Self {
+        Self { label: label.into(), commitment, degree_bound }
     }
 
     pub fn new_with_info(info: &PolynomialInfo, commitment: C) -> Self {
-        Self { label: info.label().to_string(), commitment, degree_bound: info.degree_bound() }
+        Self { label: info.label.clone(), commitment, degree_bound: info.degree_bound() }
     }
 
     /// Return the label for `self`.
@@ -453,6 +842,11 @@ impl<C: CanonicalSerialize> LabeledCommitment<C> {
         &self.label
     }
 
+    /// Return the label for `self` as a [`PolynomialLabel`], which can be cloned without allocating.
+    pub fn polynomial_label(&self) -> &PolynomialLabel {
+        &self.label
+    }
+
     /// Retrieve the commitment from `self`.
     pub fn commitment(&self) -> &C {
         &self.commitment
@@ -470,7 +864,7 @@ pub enum LCTerm {
     /// The constant term representing `one`.
     One,
     /// Label for a polynomial.
-    PolyLabel(String),
+    PolyLabel(PolynomialLabel),
 }
 
 impl fmt::Debug for LCTerm {
@@ -502,6 +896,18 @@ impl<'a> From<&'a str> for LCTerm {
     }
 }
 
+impl From<String> for LCTerm {
+    fn from(other: String) -> Self {
+        Self::PolyLabel(other.into())
+    }
+}
+
+impl From<&String> for LCTerm {
+    fn from(other: &String) -> Self {
+        Self::PolyLabel(other.into())
+    }
+}
+
 impl core::convert::TryInto<PolynomialLabel> for LCTerm {
     type Error = ();
 
@@ -537,7 +943,7 @@ impl<B: Borrow<String>> PartialEq<B> for LCTerm {
 #[derive(Clone, Debug)]
 pub struct LinearCombination<F> {
     /// The label.
-    pub label: String,
+    pub label: PolynomialLabel,
     /// The linear combination of `(coeff, poly_label)` pairs.
     pub terms: BTreeMap<LCTerm, F>,
 }
@@ -545,13 +951,13 @@ pub struct LinearCombination<F> {
 #[allow(clippy::or_fun_call)]
 impl<F: Field> LinearCombination<F> {
     /// Construct an empty labeled linear combination.
-    pub fn empty(label: impl Into<String>) -> Self {
+    pub fn empty(label: impl Into<PolynomialLabel>) -> Self {
         Self { label: label.into(), terms: BTreeMap::new() }
     }
 
     /// Construct a new labeled linear combination.
     /// with the terms specified in `term`.
-    pub fn new(label: impl Into<String>, _terms: impl IntoIterator<Item = (F, impl Into<LCTerm>)>) -> Self {
+    pub fn new(label: impl Into<PolynomialLabel>, _terms: impl IntoIterator<Item = (F, impl Into<LCTerm>)>) -> Self {
         let mut terms = BTreeMap::new();
         for (c, l) in _terms.into_iter().map(|(c, t)| (c, t.into())) {
             *terms.entry(l).or_insert(F::zero()) += c;
@@ -565,6 +971,11 @@ impl<F: Field> LinearCombination<F> {
         &self.label
     }
 
+    /// Returns the label of the linear combination as a [`PolynomialLabel`].
+    pub fn polynomial_label(&self) -> &PolynomialLabel {
+        &self.label
+    }
+
     /// Returns `true` if the linear combination has no terms.
     pub fn is_empty(&self) -> bool {
         self.terms.is_empty()
@@ -647,23 +1058,23 @@ impl<F: Field> MulAssign<F> for LinearCombination<F> {
 /// that `p[label]` is to be queried at.
 ///
 /// Added the third field: the point name.
-pub type QuerySet<T> = BTreeSet<(String, (String, T))>;
+pub type QuerySet<T> = BTreeSet<(PolynomialLabel, (String, T))>;
 
 /// `Evaluations` is the result of querying a set of labeled polynomials or equations
 /// `p` at a `QuerySet` `Q`. It maps each element of `Q` to the resulting evaluation.
 /// That is, if `(label, query)` is an element of `Q`, then `evaluation.get((label, query))`
 /// should equal `p[label].evaluate(query)`.
-pub type Evaluations<F> = BTreeMap<(String, F), F>;
+pub type Evaluations<F> = BTreeMap<(PolynomialLabel, F), F>;
 
 /// Evaluate the given polynomials at `query_set`.
 pub fn evaluate_query_set<'a, F: PrimeField>(
     polys: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
     query_set: &QuerySet<F>,
 ) -> Evaluations<F> {
-    let polys: HashMap<_, _> = polys.into_iter().map(|p| (p.label(), p)).collect();
+    let polys: HashMap<_, _> = polys.into_iter().map(|p| (p.polynomial_label(), p)).collect();
     let mut evaluations = Evaluations::new();
     for (label, (_point_name, point)) in query_set {
-        let poly = polys.get(label as &str).expect("polynomial in evaluated lc is not found");
+        let poly = polys.get(label).expect("polynomial in evaluated lc is not found");
         let eval = poly.evaluate(*point);
         evaluations.insert((label.clone(), *point), eval);
     }
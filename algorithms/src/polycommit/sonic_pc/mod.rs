@@ -13,9 +13,14 @@
 // limitations under the License.
 
 use crate::{
-    fft::DensePolynomial,
+    fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, Polynomial, SparsePolynomial},
     msm::variable_base::VariableBase,
-    polycommit::{kzg10, optional_rng::OptionalRng, PCError},
+    polycommit::{
+        kzg10::{self, VerifierCommitment},
+        optional_rng::OptionalRng,
+        PCError,
+        PolyCommitError,
+    },
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
 };
@@ -24,7 +29,7 @@ use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
 use rand_core::{RngCore, SeedableRng};
 use std::{
@@ -38,6 +43,9 @@ pub use data_structures::*;
 mod polynomial;
 pub use polynomial::*;
 
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_vectors;
+
 /// Polynomial commitment based on [\[KZG10\]][kzg], with degree enforcement and
 /// batching taken from [[MBKM19, “Sonic”]][sonic] (more precisely, their
 /// counterparts in [[Gabizon19, “AuroraLight”]][al] that avoid negative G1 powers).
@@ -137,7 +145,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             if size > pp.max_degree() + 1 {
                 bail!("The Lagrange basis size ({size}) is larger than the supported degree ({})", pp.max_degree() + 1)
             }
-            let domain = crate::fft::EvaluationDomain::new(size).unwrap();
+            let domain = crate::fft::EvaluationDomain::new(size).ok_or(PolyCommitError::UnsupportedDomainSize(size))?;
             let lagrange_basis_at_beta_g = pp.lagrange_basis(domain)?;
             assert!(lagrange_basis_at_beta_g.len().is_power_of_two());
             lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g);
@@ -171,6 +179,12 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     ///
     /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
     /// polynomial will have the corresponding degree bound enforced.
+    /// No separate shift commitment is needed for this: the returned commitment is itself a
+    /// commitment to the shifted polynomial `x^(max_degree - degree_bound) * p(x)`, where
+    /// `max_degree` is that of the SRS (see [`LabeledPolynomial::shifted_for_degree_bound`]),
+    /// and is labeled with its degree bound, so that `check` and `batch_check` pair it with
+    /// the matching negative power of `beta * h`.
+    /// Committing fails with `PCError::IncorrectDegreeBound` if `p` exceeds its degree bound.
     #[allow(clippy::type_complexity)]
     #[allow(clippy::format_push_string)]
     pub fn commit<'b>(
@@ -199,7 +213,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             )?;
             let degree_bound = p.degree_bound();
             let hiding_bound = p.hiding_bound();
-            let label = p.label().to_string();
+            let label = p.info.label.clone();
 
             pool.add_job(move || {
                 let mut rng = seed.map(rand::rngs::StdRng::from_seed);
@@ -211,14 +225,18 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                     hiding_bound,
                 ));
 
+                // The zeros that `sum` pads hiding polynomials with do not change the commitment,
+                // so they are dropped before committing.
                 #[allow(clippy::or_fun_call)]
                 let (comm, rand) = p
                     .sum()
+                    .map(PolynomialWithBasis::into_canonical)
                     .map(move |p| {
                         let rng_ref = rng.as_mut().map(|s| s as _);
                         match p {
                             PolynomialWithBasis::Lagrange { evaluations } => {
-                                let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
+                                let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len())
+                                    .expect("the evaluations are already over a domain of this size");
                                 let lagrange_basis = ck
                                     .lagrange_basis(domain)
                                     .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
@@ -233,13 +251,19 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                             }
                             PolynomialWithBasis::Monomial { polynomial, degree_bound } => {
                                 let powers = if let Some(degree_bound) = degree_bound {
-                                    ck.shifted_powers_of_beta_g(degree_bound).unwrap()
+                                    ck.shifted_powers_of_beta_g(degree_bound)
+                                        .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
                                 } else {
                                     ck.powers()
                                 };
 
                                 kzg10::KZG10::commit(&powers, &polynomial, hiding_bound, rng_ref)
                             }
+                            PolynomialWithBasis::Power { degree, coeff } => {
+                                let polynomial =
+                                    Polynomial::from(SparsePolynomial::from_coefficients([(degree, coeff)]));
+                                kzg10::KZG10::commit(&ck.powers(), &polynomial, hiding_bound, rng_ref)
+                            }
                         }
                     })
                     .collect::<Result<Vec<_>, _>>()?
@@ -251,7 +275,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                     });
                 let comm = kzg10::KZGCommitment(comm.to_affine());
 
-                Ok((LabeledCommitment::new(label.to_string(), comm, degree_bound), rand))
+                Ok((LabeledCommitment::new(label, comm, degree_bound), rand))
             });
         }
         let results: Vec<Result<_, PCError>> = pool.execute_all();
@@ -265,6 +289,51 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
+    /// Commits to each polynomial in `polynomials` as in [`Self::commit`], additionally
+    /// recording the time taken and the MSM size for each polynomial, keyed by label.
+    ///
+    /// The polynomials are committed to one at a time so that the timings are not
+    /// distorted by other commitments running concurrently.
+    #[cfg(feature = "metrics")]
+    #[allow(clippy::type_complexity)]
+    pub fn commit_with_metrics<'b>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, E::Fr>>,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<
+        (Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>, BTreeMap<PolynomialLabel, CommitMetrics>),
+        PCError,
+    > {
+        let mut labeled_comms = Vec::new();
+        let mut randomness = Vec::new();
+        let mut metrics = BTreeMap::new();
+        for p in polynomials {
+            let label = p.info.label.clone();
+            let msm_size = p
+                .sum()
+                .map(PolynomialWithBasis::into_canonical)
+                .map(|p| match p {
+                    PolynomialWithBasis::Lagrange { evaluations } => evaluations.evaluations.len(),
+                    PolynomialWithBasis::Monomial { polynomial, .. } => match polynomial.as_ref() {
+                        crate::fft::Polynomial::Dense(p) => p.coeffs.len(),
+                        crate::fft::Polynomial::Sparse(p) => p.coeffs().count(),
+                    },
+                    PolynomialWithBasis::Power { .. } => 1,
+                })
+                .sum();
+
+            let start = std::time::Instant::now();
+            let (comm, rand) = Self::commit(universal_prover, ck, [p], rng.as_mut().map(|r| &mut **r as _))?;
+            let duration = start.elapsed();
+
+            labeled_comms.extend(comm);
+            randomness.extend(rand);
+            metrics.insert(label, CommitMetrics { duration, msm_size });
+        }
+        Ok((labeled_comms, randomness, metrics))
+    }
+
     pub fn combine_for_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -276,14 +345,18 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Randomness<E>: 'a,
         Commitment<E>: 'a,
     {
-        Ok(Self::combine_polynomials(labeled_polynomials.into_iter().zip_eq(rands).map(|(p, r)| {
-            let enforced_degree_bounds: Option<&[usize]> = ck.enforced_degree_bounds.as_deref();
+        let coeffs_polys_rands = labeled_polynomials
+            .into_iter()
+            .zip_eq(rands)
+            .map(|(p, r)| {
+                let enforced_degree_bounds: Option<&[usize]> = ck.enforced_degree_bounds.as_deref();
 
-            kzg10::KZG10::<E>::check_degrees_and_bounds(universal_prover.max_degree, enforced_degree_bounds, p)
-                .unwrap();
-            let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
-            (challenge, p.polynomial().to_dense(), r)
-        })))
+                kzg10::KZG10::<E>::check_degrees_and_bounds(universal_prover.max_degree, enforced_degree_bounds, p)?;
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                Ok((challenge, p.polynomial().to_dense(), r))
+            })
+            .collect::<Result<Vec<_>, PCError>>()?;
+        Ok(Self::combine_polynomials(coeffs_polys_rands))
     }
 
     /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
@@ -305,7 +378,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .into_iter()
             .zip_eq(rands)
             .zip_eq(commitments.into_iter())
-            .map(|((poly, r), comm)| (poly.label(), (poly, r, comm)))
+            .map(|((poly, r), comm)| (poly.polynomial_label(), (poly, r, comm)))
             .collect();
 
         let open_time = start_timer!(|| format!(
@@ -329,7 +402,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
             for label in labels {
                 let (polynomial, rand, comm) =
-                    poly_rand_comm.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                    poly_rand_comm.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
 
                 query_polys.push(*polynomial);
                 query_rands.push(*rand);
@@ -351,18 +424,15 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         batch_proof
     }
 
-    pub fn batch_check<'a>(
+    pub fn batch_check<'a, C: VerifierCommitment<E>>(
         vk: &UniversalVerifier<E>,
-        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<C>>,
         query_set: &QuerySet<E::Fr>,
         values: &Evaluations<E::Fr>,
         proof: &BatchProof<E>,
         fs_rng: &mut S,
-    ) -> Result<bool, PCError>
-    where
-        Commitment<E>: 'a,
-    {
-        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+    ) -> Result<bool, PCError> {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.polynomial_label(), c)).collect();
         let batch_check_time = start_timer!(|| format!(
             "Checking {} commitments at query set of size {}",
             commitments.len(),
@@ -419,6 +489,72 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         result
     }
 
+    /// Checks `proof` as in [`Self::batch_check`], returning an error if the check fails.
+    ///
+    /// If `identify_failures` is set and the check fails, the openings in `proof` are then
+    /// rechecked one at a time, and the error reports the indices of those that fail.
+    /// The openings are ordered by point name. As this requires a pairing check per opening,
+    /// it should only be used for debugging.
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_check_detailed<'a, C: VerifierCommitment<E>>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<C>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+        identify_failures: bool,
+    ) -> Result<(), PCError> {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.polynomial_label(), c)).collect();
+        let mut replay_fs_rng = fs_rng.clone();
+        if Self::batch_check(vk, commitments.values().copied(), query_set, values, proof, fs_rng)? {
+            return Ok(());
+        }
+
+        let mut failed_openings = Vec::new();
+        if identify_failures {
+            let mut query_to_labels_map = BTreeMap::new();
+            for (label, (point_name, point)) in query_set.iter() {
+                let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+                labels.1.insert(label);
+            }
+
+            // Replay the sponge as in `batch_check`, checking each opening on its own.
+            for (i, ((_query_name, (query, labels)), p)) in query_to_labels_map.into_iter().zip_eq(&proof.0).enumerate()
+            {
+                let mut comms_to_combine = Vec::new();
+                let mut values_to_combine = Vec::new();
+                for label in labels.into_iter() {
+                    comms_to_combine.push(commitments[label]);
+                    values_to_combine.push(values[&(label.clone(), *query)]);
+                }
+
+                let mut combined_comms = BTreeMap::new();
+                let mut combined_witness = E::G1Projective::zero();
+                let mut combined_adjusted_witness = E::G1Projective::zero();
+                Self::accumulate_elems(
+                    &mut combined_comms,
+                    &mut combined_witness,
+                    &mut combined_adjusted_witness,
+                    vk,
+                    comms_to_combine,
+                    *query,
+                    values_to_combine,
+                    p,
+                    None,
+                    &mut replay_fs_rng,
+                );
+                if !Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)? {
+                    failed_openings.push(i);
+                }
+
+                // Squeeze the randomizer used by `batch_check`.
+                let _ = replay_fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+            }
+        }
+        Err(PCError::BatchCheckFailed { failed_openings })
+    }
+
     pub fn open_combinations<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -437,7 +573,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .into_iter()
             .zip_eq(rands)
             .zip_eq(commitments)
-            .map(|((p, r), c)| (p.label(), (p, r, c)))
+            .map(|((p, r), c)| (p.polynomial_label(), (p, r, c)))
             .collect::<BTreeMap<_, _>>();
 
         let mut lc_polynomials = Vec::new();
@@ -446,7 +582,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         let mut lc_info = Vec::new();
 
         for lc in linear_combinations {
-            let lc_label = lc.label().to_string();
+            let lc_label = lc.polynomial_label().clone();
             let mut poly = DensePolynomial::zero();
             let mut degree_bound = None;
             let mut hiding_bound = None;
@@ -456,14 +592,14 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
             let num_polys = lc.len();
             for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
-                let label: &String = label.try_into().expect("cannot be one!");
+                let label: &PolynomialLabel = label.try_into().expect("cannot be one!");
                 let &(cur_poly, cur_rand, cur_comm) =
-                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                    label_map.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
                 if num_polys == 1 && cur_poly.degree_bound().is_some() {
                     assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
                     degree_bound = cur_poly.degree_bound();
                 } else if cur_poly.degree_bound().is_some() {
-                    return Err(PCError::EquationHasDegreeBounds(lc_label));
+                    return Err(PCError::EquationHasDegreeBounds(lc_label.to_string()));
                 }
                 // Some(_) > None, always.
                 hiding_bound = core::cmp::max(hiding_bound, cur_poly.hiding_bound());
@@ -514,7 +650,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Commitment<E>: 'a,
     {
         let BatchLCProof { proof, .. } = proof;
-        let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
+        let label_comm_map = commitments.into_iter().map(|c| (c.polynomial_label(), c)).collect::<BTreeMap<_, _>>();
 
         let mut lc_commitments = Vec::new();
         let mut lc_info = Vec::new();
@@ -522,7 +658,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let lc_processing_time = start_timer!(|| "Combining commitments");
         for lc in linear_combinations {
-            let lc_label = lc.label().to_string();
+            let lc_label = lc.polynomial_label().clone();
             let num_polys = lc.len();
 
             let mut degree_bound = None;
@@ -536,16 +672,16 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                         }
                     }
                 } else {
-                    let label: &String = label.try_into().unwrap();
+                    let label: &PolynomialLabel = label.try_into().unwrap();
                     let &cur_comm = label_comm_map
-                        .get(label as &str)
+                        .get(label)
                         .ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
 
                     if num_polys == 1 && cur_comm.degree_bound().is_some() {
                         assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
                         degree_bound = cur_comm.degree_bound();
                     } else if cur_comm.degree_bound().is_some() {
-                        return Err(PCError::EquationHasDegreeBounds(lc_label));
+                        return Err(PCError::EquationHasDegreeBounds(lc_label.to_string()));
                     }
                     coeffs_and_comms.push((*coeff, cur_comm.commitment()));
                 }
@@ -568,6 +704,116 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
+
+    /// Commit to `values` as the evaluations of a polynomial over the smallest domain that can hold them,
+    /// padding them with zeros up to the size of that domain. This allows a vector to be committed
+    /// without constructing a `LabeledPolynomial`. The commitment is hiding if `hiding_bound` is `Some`.
+    /// `ck` must support the Lagrange basis for the size of the domain.
+    ///
+    /// Returns the commitment, its randomness, and the padded evaluations, which are needed by
+    /// [`Self::open_vector`] to open the commitment at an index.
+    pub fn commit_vector(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        values: &[E::Fr],
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>, EvaluationsOnDomain<E::Fr>), PCError> {
+        let domain = EvaluationDomain::new(values.len()).ok_or(PolyCommitError::UnsupportedDomainSize(values.len()))?;
+        let mut evaluations = values.to_vec();
+        evaluations.resize(domain.size(), E::Fr::zero());
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain(evaluations, domain);
+
+        let polynomial =
+            LabeledPolynomialWithBasis::new_lagrange_basis_ref("vector".to_string(), &evaluations, hiding_bound);
+        let (mut commitments, mut rands) = Self::commit(universal_prover, ck, [polynomial], rng)?;
+        let (commitment, rand) = (commitments.remove(0), rands.remove(0));
+        Ok((*commitment.commitment(), rand, evaluations))
+    }
+
+    /// Open a commitment produced by [`Self::commit_vector`] at `index`, returning the value at `index`
+    /// and a proof that it is the evaluation of the committed polynomial at the `index`-th element of the domain.
+    pub fn open_vector(
+        ck: &CommitterUnionKey<E>,
+        evaluations: &EvaluationsOnDomain<E::Fr>,
+        rand: &Randomness<E>,
+        index: usize,
+    ) -> Result<(E::Fr, kzg10::KZGProof<E>), PCError> {
+        let value = *evaluations.evaluations.get(index).ok_or_else(|| {
+            anyhow!("The index {index} is out of bounds for a vector of length {}", evaluations.evaluations.len())
+        })?;
+        let point = evaluations.domain().element(index);
+        let proof = kzg10::KZG10::open(&ck.powers(), &evaluations.interpolate_by_ref(), point, rand)?;
+        Ok((value, proof))
+    }
+
+    /// Check that `value` is the value at `index` of the vector committed to in `commitment`,
+    /// which was produced by [`Self::commit_vector`] over `domain`.
+    pub fn check_vector(
+        vk: &UniversalVerifier<E>,
+        commitment: &Commitment<E>,
+        domain: EvaluationDomain<E::Fr>,
+        index: usize,
+        value: E::Fr,
+        proof: &kzg10::KZGProof<E>,
+    ) -> Result<bool, PCError> {
+        if index >= domain.size() {
+            return Ok(false);
+        }
+        kzg10::KZG10::check(&vk.vk, commitment, domain.element(index), value, proof)
+    }
+
+    /// Open `polynomials` at `point` with a single proof, by opening the combination
+    /// `sum_i challenge^i * polynomials[i]`, whose randomness is the same combination of `rands`.
+    /// The polynomials must not have degree bounds.
+    ///
+    /// For the proof to be sound, `challenge` must be derived by Fiat–Shamir from a transcript to which the
+    /// commitments, `point`, and the claimed evaluations have already been added, e.g. by absorbing them into a
+    /// sponge and then squeezing `challenge`. A challenge known before the evaluations are fixed would allow
+    /// a prover to choose false evaluations whose errors cancel out in the combination.
+    pub fn open_combined(
+        ck: &CommitterUnionKey<E>,
+        polynomials: &[LabeledPolynomial<E::Fr>],
+        rands: &[Randomness<E>],
+        point: E::Fr,
+        challenge: E::Fr,
+    ) -> Result<BatchProof<E>, PCError> {
+        if polynomials.len() != rands.len() {
+            return Err(anyhow!("Expected {} randomness values, but found {}", polynomials.len(), rands.len()).into());
+        }
+        if let Some(degree_bound) = polynomials.iter().find_map(|p| p.degree_bound()) {
+            return Err(PCError::UnsupportedDegreeBound(degree_bound));
+        }
+        let challenges = core::iter::successors(Some(E::Fr::one()), |c| Some(*c * challenge));
+        let (polynomial, rand) = Self::combine_polynomials(
+            challenges.zip(polynomials).zip(rands).map(|((c, p), r)| (c, p.polynomial().to_dense(), r)),
+        );
+        let proof = kzg10::KZG10::open(&ck.powers(), &polynomial, point, &rand)?;
+        Ok(BatchProof(vec![proof]))
+    }
+
+    /// Check a proof produced by [`Self::open_combined`] that `values[i]` is the evaluation at `point` of the
+    /// polynomial committed to in `commitments[i]`, for each `i`. This costs two pairings, regardless of the number
+    /// of commitments. See [`Self::open_combined`] for how `challenge` must be derived.
+    pub fn verify_combined<C: VerifierCommitment<E>>(
+        vk: &UniversalVerifier<E>,
+        commitments: &[C],
+        point: E::Fr,
+        values: &[E::Fr],
+        challenge: E::Fr,
+        proof: &BatchProof<E>,
+    ) -> Result<bool, PCError> {
+        if commitments.len() != values.len() {
+            return Err(anyhow!("Expected {} evaluations, but found {}", commitments.len(), values.len()).into());
+        }
+        if proof.0.len() != 1 {
+            return Ok(false);
+        }
+        let challenges = core::iter::successors(Some(E::Fr::one()), |c| Some(*c * challenge));
+        let commitment = C::linear_combination(challenges.clone().zip(commitments)).to_affine();
+        let value = challenges.zip(values).map(|(c, v)| c * v).sum();
+        kzg10::KZG10::check(&vk.vk, &kzg10::KZGCommitment(commitment), point, value, &proof.0[0])
+    }
 }
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
@@ -605,12 +851,12 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     #[allow(clippy::too_many_arguments)]
-    fn accumulate_elems<'a>(
+    fn accumulate_elems<'a, C: VerifierCommitment<E>>(
         combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
         combined_witness: &mut E::G1Projective,
         combined_adjusted_witness: &mut E::G1Projective,
         vk: &UniversalVerifier<E>,
-        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<C>>,
         point: E::Fr,
         values: impl IntoIterator<Item = E::Fr>,
         proof: &kzg10::KZGProof<E>,
@@ -633,7 +879,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
             // Applying opening challenge and randomness (used in batch_checking)
             let coeff = randomizer.unwrap_or_else(E::Fr::one) * curr_challenge;
-            let comm_with_challenge: E::G1Projective = comm.0.mul(coeff);
+            let comm_with_challenge: E::G1Projective = comm.scale(coeff);
 
             // Accumulate values in the BTreeMap
             *combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &comm_with_challenge;
@@ -735,6 +981,561 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_commit_with_metrics() {
+        use super::{CommitterUnionKey, LabeledPolynomial};
+        use crate::fft::DensePolynomial;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = (0..4)
+            .map(|i| LabeledPolynomial::new(format!("Test{i}"), DensePolynomial::rand(8 * i, rng), None, Some(1)))
+            .collect::<Vec<_>>();
+        let (comms, _rands, metrics) =
+            PC_Bls12_377::commit_with_metrics(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng))
+                .unwrap();
+        assert_eq!(comms.len(), polynomials.len());
+        for (comm, p) in comms.iter().zip(&polynomials) {
+            assert_eq!(comm.label(), p.label());
+            assert_eq!(metrics[p.label()].msm_size, p.coeffs().count());
+        }
+    }
+
+    #[test]
+    fn test_committer_key_update_append() {
+        use super::LabeledPolynomial;
+        use crate::{fft::DensePolynomial, polycommit::PCError};
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, None, 0, None).unwrap();
+        let commit = |p: &DensePolynomial<Fr>| {
+            ck.commit_batch(&[LabeledPolynomial::new("p", p.clone(), None, None)]).unwrap()[0]
+        };
+
+        let mut p = DensePolynomial::<Fr>::rand(10, rng);
+        let mut commitment = commit(&p);
+        let new_coeffs = (0..5).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        ck.update_append(&mut commitment, &new_coeffs, p.coeffs.len()).unwrap();
+        p.coeffs.extend(new_coeffs);
+        assert_eq!(commitment, commit(&p));
+
+        let num_powers = ck.powers_of_beta_g.len();
+        assert!(matches!(
+            ck.update_append(&mut commitment, &[Fr::rand(rng)], num_powers),
+            Err(PCError::TooManyCoefficients { .. })
+        ));
+    }
+
+    #[test]
+    fn test_split_hiding() {
+        use super::{CommitterUnionKey, LabeledPolynomial};
+        use crate::{
+            fft::{DensePolynomial, Polynomial},
+            polycommit::kzg10,
+        };
+        use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, None, 1, None).unwrap();
+        let ck_union = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let base = DensePolynomial::rand(10, rng);
+        let public = LabeledPolynomial::new("public", base.clone(), None, None);
+        let hiding = LabeledPolynomial::new("hiding", base.clone(), None, Some(1));
+        let (comms, rands) =
+            PC_Bls12_377::commit(universal_prover, &ck_union, [(&public).into(), (&hiding).into()], Some(rng)).unwrap();
+        assert_eq!(public.split_hiding(&rands[0]), (base.clone(), None));
+        let (unmasked, mask) = hiding.split_hiding(&rands[1]);
+        let mask = mask.unwrap();
+        assert_eq!(unmasked, base);
+        assert_eq!(mask, rands[1].blinding_polynomial);
+
+        // The hiding commitment is the commitment to the polynomial, blinded by the mask.
+        let gamma_powers = kzg10::Powers::<Bls12_377> {
+            powers_of_beta_g: ck.powers_of_beta_times_gamma_g.as_slice().into(),
+            powers_of_beta_times_gamma_g: (&[][..]).into(),
+        };
+        let (blinding, _) = kzg10::KZG10::commit(&gamma_powers, &Polynomial::from(mask), None, None).unwrap();
+        let expected = comms[0].commitment().0.to_projective() + blinding.0.to_projective();
+        assert_eq!(comms[1].commitment().0, expected.to_affine());
+    }
+
+    #[test]
+    fn test_commit_batch() {
+        use super::{Commitment, CommitterUnionKey, LabeledPolynomial};
+        use crate::{
+            fft::{DensePolynomial, SparsePolynomial},
+            polycommit::PCError,
+        };
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(64).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 64, None, 1, None).unwrap();
+        let ck_union = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let mut polynomials = [0, 1, 10, 40, 64]
+            .into_iter()
+            .enumerate()
+            .map(|(i, degree)| LabeledPolynomial::new(format!("p{i}"), DensePolynomial::rand(degree, rng), None, None))
+            .collect::<Vec<_>>();
+        polynomials.push(LabeledPolynomial::new("sparse", SparsePolynomial::rand(3, 50, rng), None, None));
+        polynomials.push(LabeledPolynomial::new("zero", DensePolynomial::zero(), None, None));
+
+        let batched = ck.commit_batch(&polynomials).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (individual, _) =
+            PC_Bls12_377::commit(universal_prover, &ck_union, polynomials.iter().map(Into::into), None).unwrap();
+        assert_eq!(batched, individual.iter().map(|c| *c.commitment()).collect::<Vec<_>>());
+        assert!(ck.commit_batch(&[]).unwrap().is_empty());
+
+        // A batch of zero polynomials needs no bases.
+        let zeros = (0..3).map(|i| LabeledPolynomial::new(format!("z{i}"), DensePolynomial::zero(), None, None));
+        let zeros = zeros.collect::<Vec<_>>();
+        assert_eq!(ck.commit_batch(&zeros).unwrap(), vec![Commitment::empty(); 3]);
+
+        // Hiding, degree-bounded and oversized polynomials are rejected.
+        let hiding = LabeledPolynomial::new("hiding", DensePolynomial::rand(4, rng), None, Some(1));
+        assert!(matches!(ck.commit_batch(&[hiding]), Err(PCError::MissingRng)));
+        let bounded = LabeledPolynomial::new("bounded", DensePolynomial::rand(4, rng), Some(14), None);
+        assert!(matches!(ck.commit_batch(&[bounded]), Err(PCError::UnsupportedDegreeBound(14))));
+        let large = LabeledPolynomial::new("large", DensePolynomial::rand(100, rng), None, None);
+        assert!(matches!(ck.commit_batch(&[large]), Err(PCError::TooManyCoefficients { .. })));
+    }
+
+    #[test]
+    fn test_commit_to_zero_polynomial() {
+        use super::{Commitment, CommitterUnionKey, Evaluations, LabeledPolynomial, QuerySet};
+        use crate::{
+            fft::{DensePolynomial, SparsePolynomial},
+            AlgebraicSponge,
+        };
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_fields::Zero;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, 32, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        // The zero polynomial, as an empty dense polynomial, a sparse polynomial, and a dense polynomial
+        // that is padded with zero coefficients, each committed with and without hiding.
+        let mut padded = DensePolynomial::zero();
+        padded.resize_to_degree(8).unwrap();
+        assert_eq!(padded.coeffs.len(), 9);
+        let mut polynomials = Vec::new();
+        for hiding_bound in [None, Some(1)] {
+            let suffix = if hiding_bound.is_some() { "_hiding" } else { "" };
+            polynomials.push(LabeledPolynomial::new(
+                format!("dense{suffix}"),
+                DensePolynomial::zero(),
+                None,
+                hiding_bound,
+            ));
+            polynomials.push(LabeledPolynomial::new(
+                format!("sparse{suffix}"),
+                SparsePolynomial::zero(),
+                None,
+                hiding_bound,
+            ));
+            polynomials.push(LabeledPolynomial::new(format!("padded{suffix}"), padded.clone(), None, hiding_bound));
+        }
+        let (comms, rands) =
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+        assert!(Commitment::<Bls12_377>::zero().is_zero());
+        for (comm, p) in comms.iter().zip(&polynomials) {
+            assert_eq!(comm.commitment().is_zero(), !p.is_hiding(), "{}", p.label());
+        }
+
+        // Openings of the zero polynomial at any point are accepted with the evaluation zero, and only with it.
+        for point in [Fr::zero(), Fr::rand(rng)] {
+            let mut query_set = QuerySet::new();
+            let mut values = Evaluations::new();
+            for p in &polynomials {
+                query_set.insert((p.polynomial_label().clone(), ("point".to_string(), point)));
+                values.insert((p.polynomial_label().clone(), point), Fr::zero());
+            }
+            let proof = PC_Bls12_377::batch_open(
+                universal_prover,
+                &ck,
+                &polynomials,
+                &comms,
+                &query_set,
+                &rands,
+                &mut Sponge::new(),
+            )
+            .unwrap();
+            assert!(PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+
+            values.insert(("dense".into(), point), Fr::rand(rng));
+            assert!(!PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_select_key() {
+        use super::{select_key, LabeledPolynomial};
+        use crate::{fft::DensePolynomial, polycommit::PCError};
+
+        let rng = &mut TestRng::default();
+        let trim = |degree, hiding_bound, bounds| {
+            let pp = PC_Bls12_377::load_srs(degree).unwrap();
+            PC_Bls12_377::trim(&pp, degree, None, hiding_bound, bounds).unwrap().0
+        };
+        let keys = vec![trim(64, 1, None), trim(16, 1, None), trim(32, 1, Some(&[14][..]))];
+        let mut poly = |degree, degree_bound, hiding_bound| {
+            LabeledPolynomial::new("p", DensePolynomial::rand(degree, rng), degree_bound, hiding_bound)
+        };
+
+        // The smallest sufficient key is selected, regardless of the order of the keys.
+        let small = poly(10, None, None);
+        assert_eq!(select_key(&keys, std::slice::from_ref(&small)).unwrap().supported_degree(), 16);
+        let bounded = poly(5, Some(14), None);
+        assert_eq!(select_key(&keys, &[small.clone(), bounded.clone()]).unwrap().supported_degree(), 32);
+        let large = poly(40, None, None);
+        assert_eq!(select_key(&keys, &[small, large.clone()]).unwrap().supported_degree(), 64);
+
+        // No key supports both a large polynomial and a degree bound, nor a hiding bound that is too large.
+        assert!(matches!(
+            select_key(&keys, &[bounded, large]),
+            Err(PCError::NoSufficientCommitterKey(reason)) if matches!(*reason, PCError::UnsupportedDegreeBound(14))
+        ));
+        let hiding = poly(4, None, keys.iter().map(|key| key.powers_of_beta_times_gamma_g.len()).max());
+        assert!(matches!(
+            select_key(&keys, std::slice::from_ref(&hiding)),
+            Err(PCError::NoSufficientCommitterKey(reason)) if matches!(*reason, PCError::HidingBoundToolarge { .. })
+        ));
+        assert!(matches!(select_key::<Bls12_377>(&[], &[poly(0, None, None)]), Err(PCError::NoCommitterKeys)));
+    }
+
+    #[test]
+    fn test_vector_commitment() {
+        use super::CommitterUnionKey;
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, 32, [8], 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let values = (0..5).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        for hiding_bound in [None, Some(1)] {
+            let (commitment, rand, evaluations) =
+                PC_Bls12_377::commit_vector(universal_prover, &ck, &values, hiding_bound, Some(rng)).unwrap();
+            let domain = evaluations.domain();
+            assert_eq!(domain.size(), 8);
+            assert_eq!(&evaluations.evaluations[..5], &values[..]);
+
+            for index in 0..domain.size() {
+                let (value, proof) = PC_Bls12_377::open_vector(&ck, &evaluations, &rand, index).unwrap();
+                assert_eq!(value, values.get(index).copied().unwrap_or_default());
+                assert!(PC_Bls12_377::check_vector(&vk, &commitment, domain, index, value, &proof).unwrap());
+                let wrong_value = value + Fr::from(1u8);
+                assert!(!PC_Bls12_377::check_vector(&vk, &commitment, domain, index, wrong_value, &proof).unwrap());
+            }
+            assert!(PC_Bls12_377::open_vector(&ck, &evaluations, &rand, 8).is_err());
+        }
+    }
+
+    #[test]
+    fn test_open_combined() {
+        use super::{CommitterUnionKey, LabeledPolynomial};
+        use crate::fft::DensePolynomial;
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, 32, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = (0..4)
+            .map(|i| {
+                let hiding_bound = if i % 2 == 0 { None } else { Some(1) };
+                let p = DensePolynomial::<Fr>::rand(8 + i, rng);
+                LabeledPolynomial::new(format!("p{i}"), p, None, hiding_bound)
+            })
+            .collect::<Vec<_>>();
+        let (comms, rands) =
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+        let comms = comms.into_iter().map(|c| *c.commitment()).collect::<Vec<_>>();
+
+        let point = Fr::rand(rng);
+        let challenge = Fr::rand(rng);
+        let values = polynomials.iter().map(|p| p.evaluate(point)).collect::<Vec<_>>();
+        let proof = PC_Bls12_377::open_combined(&ck, &polynomials, &rands, point, challenge).unwrap();
+        assert!(PC_Bls12_377::verify_combined(&vk, &comms, point, &values, challenge, &proof).unwrap());
+
+        let wrong_challenge = challenge + Fr::from(1u8);
+        assert!(!PC_Bls12_377::verify_combined(&vk, &comms, point, &values, wrong_challenge, &proof).unwrap());
+        let mut wrong_values = values.clone();
+        wrong_values[2] += Fr::from(1u8);
+        assert!(!PC_Bls12_377::verify_combined(&vk, &comms, point, &wrong_values, challenge, &proof).unwrap());
+        assert!(PC_Bls12_377::verify_combined(&vk, &comms, point, &values[1..], challenge, &proof).is_err());
+        assert!(PC_Bls12_377::open_combined(&ck, &polynomials, &rands[1..], point, challenge).is_err());
+    }
+
+    #[test]
+    fn test_committer_key_lagrange_commitment() {
+        use super::LabeledPolynomial;
+        use crate::{
+            fft::{EvaluationDomain, Evaluations},
+            polycommit::PCError,
+        };
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, [8, 32], 0, None).unwrap();
+
+        for size in [8, 32] {
+            let domain = EvaluationDomain::new(size).unwrap();
+            let evaluations = Evaluations::from_vec_and_domain((0..size).map(|_| Fr::rand(rng)).collect(), domain);
+            let polynomial = LabeledPolynomial::new("p", evaluations.interpolate_by_ref(), None, None);
+            let expected = ck.commit_batch(&[polynomial]).unwrap()[0];
+            assert_eq!(ck.lagrange_commitment(&evaluations).unwrap(), expected);
+        }
+
+        let domain = EvaluationDomain::new(16).unwrap();
+        let evaluations = Evaluations::from_vec_and_domain(vec![Fr::rand(rng); 16], domain);
+        assert!(matches!(ck.lagrange_commitment(&evaluations), Err(PCError::UnsupportedLagrangeBasisSize(16))));
+    }
+
+    #[test]
+    fn test_commit_with_degree_bound_is_shifted() {
+        use super::{CommitterUnionKey, LabeledPolynomial};
+        use crate::{fft::DensePolynomial, msm::VariableBase, polycommit::PCError};
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_fields::PrimeField;
+
+        let rng = &mut TestRng::default();
+        let max_degree = 32;
+        let degree_bound = 14;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 0, Some(&[degree_bound])).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        // The commitment to a degree-bounded polynomial is the commitment to its shift,
+        // i.e. the MSM of its coefficients with the powers of beta starting at `max_degree - degree_bound`.
+        let p = DensePolynomial::rand(degree_bound, rng);
+        let p = LabeledPolynomial::new("p".to_string(), p, Some(degree_bound), None);
+        let (comms, _) = PC_Bls12_377::commit(universal_prover, &ck, [(&p).into()], None).unwrap();
+        assert_eq!(comms[0].degree_bound(), Some(degree_bound));
+        let shifted_bases = pp.powers_of_beta_g(pp.max_degree() - degree_bound, pp.max_degree() + 1).unwrap();
+        let scalars = p.coeffs().map(|(_, c)| c.to_bigint()).collect::<Vec<_>>();
+        let expected = VariableBase::msm(&shifted_bases[..scalars.len()], &scalars).to_affine();
+        assert_eq!(comms[0].commitment().0, expected);
+
+        // A polynomial that exceeds its degree bound cannot be committed to.
+        let p = DensePolynomial::rand(degree_bound + 1, rng);
+        let p = LabeledPolynomial::new("p".to_string(), p, Some(degree_bound), None);
+        assert!(matches!(
+            PC_Bls12_377::commit(universal_prover, &ck, [(&p).into()], None),
+            Err(PCError::IncorrectDegreeBound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_batch_check_detailed() {
+        use super::{CommitterUnionKey, Evaluations, LabeledPolynomial, QuerySet};
+        use crate::{fft::DensePolynomial, polycommit::PCError, AlgebraicSponge};
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, 32, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = (0..4)
+            .map(|i| LabeledPolynomial::new(format!("Test{i}"), DensePolynomial::rand(16, rng), None, Some(1)))
+            .collect::<Vec<_>>();
+        let (comms, rands) =
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        // Query the first two polynomials at one point, and the other two at another.
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for (i, p) in polynomials.iter().enumerate() {
+            let (point_name, point) = (format!("point{}", i / 2), Fr::from((i / 2 + 1) as u64));
+            query_set.insert((p.polynomial_label().clone(), (point_name, point)));
+            values.insert((p.polynomial_label().clone(), point), p.evaluate(point));
+        }
+        let sponge = &mut Sponge::new();
+        let proof =
+            PC_Bls12_377::batch_open(universal_prover, &ck, &polynomials, &comms, &query_set, &rands, sponge).unwrap();
+
+        let vk = &vk;
+        let check = |values: &Evaluations<Fr>, identify_failures| {
+            PC_Bls12_377::batch_check_detailed(
+                vk,
+                &comms,
+                &query_set,
+                values,
+                &proof,
+                &mut Sponge::new(),
+                identify_failures,
+            )
+        };
+        assert!(check(&values, true).is_ok());
+
+        // Tamper with an evaluation at the second point.
+        let mut bad_values = values.clone();
+        *bad_values.get_mut(&("Test3".into(), Fr::from(2u64))).unwrap() += Fr::rand(rng);
+        let failed_openings = |identify_failures| match check(&bad_values, identify_failures) {
+            Err(PCError::BatchCheckFailed { failed_openings }) => failed_openings,
+            _ => panic!("the batch check should fail"),
+        };
+        assert!(failed_openings(false).is_empty());
+        assert_eq!(failed_openings(true), vec![1]);
+    }
+
+    #[test]
+    fn test_label_generator() {
+        use super::{indexed, LabelGenerator};
+
+        assert_eq!(indexed("w", 3), "w_00000003");
+        let mut generator = LabelGenerator::new();
+        let labels = ["w", "z", "w", "w", "z"].map(|prefix| generator.next(prefix));
+        assert_eq!(labels, ["w_00000000", "z_00000000", "w_00000001", "w_00000002", "z_00000001"]);
+
+        // Labels sort in the order of their indices.
+        let mut generator = LabelGenerator::new();
+        let labels = (0..20).map(|_| generator.next("w")).collect::<Vec<_>>();
+        assert!(labels.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_polynomial_label() {
+        use super::{LabeledPolynomial, PolynomialLabel};
+        use crate::fft::DensePolynomial;
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize};
+        use std::collections::{BTreeMap, BTreeSet};
+
+        // Labels with the same string share their storage, however they are constructed.
+        let a = PolynomialLabel::from("interned_label");
+        let b = PolynomialLabel::from(format!("interned_{}", "label"));
+        assert_eq!(a, b);
+        assert!(a.ptr_eq(&b));
+        let p = LabeledPolynomial::new(a.clone(), DensePolynomial::<Fr>::zero(), None, None);
+        assert!(core::ptr::eq(p.label(), a.as_str()));
+        assert_eq!(p.label(), "interned_label");
+        assert_ne!(a, PolynomialLabel::from("interned_label_2"));
+
+        // Labels are ordered as their strings, and maps keyed by label can be queried with a `&str`.
+        let strings = ["w_00000010", "z", "w_00000002", "", "g_1", "W"];
+        let labels = strings.iter().map(|s| PolynomialLabel::from(*s)).collect::<BTreeSet<_>>();
+        let sorted = strings.iter().map(|s| s.to_string()).collect::<BTreeSet<_>>();
+        assert!(labels.iter().map(|l| l.as_str()).eq(sorted.iter().map(|s| s.as_str())));
+        let map = labels.iter().cloned().zip(0..).collect::<BTreeMap<_, _>>();
+        assert_eq!(map.get("g_1"), Some(&2));
+        assert_eq!(map.get("missing"), None);
+
+        // Labels are serialized as their strings.
+        let mut bytes = Vec::new();
+        a.serialize_compressed(&mut bytes).unwrap();
+        let mut string_bytes = Vec::new();
+        "interned_label".to_string().serialize_compressed(&mut string_bytes).unwrap();
+        assert_eq!(bytes, string_bytes);
+        assert_eq!(a.compressed_size(), bytes.len());
+        // Deserialized labels are equal to, but do not share the storage of, interned labels.
+        let deserialized = PolynomialLabel::deserialize_compressed(&*bytes).unwrap();
+        assert_eq!(deserialized, a);
+        assert!(!deserialized.ptr_eq(&a));
+    }
+
+    #[test]
+    fn test_polynomial_label_interner_is_bounded() {
+        use super::{data_structures::LABELS, PolynomialLabel, MAX_INTERNED_LABELS};
+
+        // Labels that are no longer in use are freed, so new labels are still interned.
+        for i in 0..2 * MAX_INTERNED_LABELS {
+            let _ = PolynomialLabel::from(format!("bounded_{i}"));
+        }
+        assert!(LABELS.read().len() <= MAX_INTERNED_LABELS);
+        let a = PolynomialLabel::from("bounded_label");
+        assert!(a.ptr_eq(&PolynomialLabel::from("bounded_label")));
+    }
+
+    #[test]
+    fn test_prepared_commitment() {
+        use super::{CommitterUnionKey, Evaluations, LabeledCommitment, LabeledPolynomial, PreparedCommitment, QuerySet};
+        use crate::{fft::DensePolynomial, polycommit::kzg10::VerifierCommitment, AlgebraicSponge};
+        use core::ops::Mul;
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_fields::{One, Zero};
+        use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, Uniform};
+
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::load_srs(32).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, 32, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = (0..3)
+            .map(|i| LabeledPolynomial::new(format!("Test{i}"), DensePolynomial::rand(16, rng), None, Some(1)))
+            .collect::<Vec<_>>();
+        let (comms, rands) =
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+        let prepared_comms = comms
+            .iter()
+            .map(|c| LabeledCommitment::new(c.label().to_string(), c.commitment().prepare(), c.degree_bound()))
+            .collect::<Vec<_>>();
+
+        // Scaling a prepared commitment matches scaling the commitment itself.
+        for scalar in [Fr::zero(), Fr::one(), -Fr::one(), Fr::rand(rng)] {
+            let prepared = prepared_comms[0].commitment();
+            assert_eq!(prepared.scale(scalar), comms[0].commitment().0.mul(scalar));
+        }
+
+        // Prepared commitments are serialized as the commitments themselves.
+        let prepared = prepared_comms[1].commitment();
+        let mut bytes = Vec::new();
+        prepared.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes, comms[1].commitment().to_bytes_le().unwrap());
+        assert_eq!(&PreparedCommitment::<Bls12_377>::deserialize_compressed(&*bytes).unwrap(), prepared);
+
+        // Both forms are accepted by the verifier.
+        let point = Fr::rand(rng);
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for p in &polynomials {
+            query_set.insert((p.polynomial_label().clone(), ("point".to_string(), point)));
+            values.insert((p.polynomial_label().clone(), point), p.evaluate(point));
+        }
+        let sponge = &mut Sponge::new();
+        let proof =
+            PC_Bls12_377::batch_open(universal_prover, &ck, &polynomials, &comms, &query_set, &rands, sponge).unwrap();
+        assert!(PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+        assert!(
+            PC_Bls12_377::batch_check(&vk, &prepared_comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap()
+        );
+
+        let challenge = Fr::rand(rng);
+        let evaluations = polynomials.iter().map(|p| p.evaluate(point)).collect::<Vec<_>>();
+        let proof = PC_Bls12_377::open_combined(&ck, &polynomials, &rands, point, challenge).unwrap();
+        let prepared = prepared_comms.iter().map(|c| c.commitment().clone()).collect::<Vec<_>>();
+        assert!(PC_Bls12_377::verify_combined(&vk, &prepared, point, &evaluations, challenge, &proof).unwrap());
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -12,13 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::PolynomialLabel;
-use crate::fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, Polynomial, SparsePolynomial};
-use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, CanonicalDeserialize, CanonicalSerialize};
+use super::{PolynomialLabel, Randomness};
+use crate::{
+    fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, Polynomial, SparsePolynomial},
+    polycommit::{PCError, PolyCommitError},
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{FftField, Field, PrimeField};
+use snarkvm_utilities::{
+    borrow::Cow,
+    cfg_chunks,
+    cfg_iter,
+    cfg_iter_mut,
+    collections::{BTreeMap, BTreeSet},
+    serialize::*,
+    vec,
+};
 
-use hashbrown::HashMap;
-use std::borrow::Cow;
+use hashbrown::{hash_map::Entry, HashMap};
 
 #[cfg(feature = "serial")]
 use itertools::Itertools;
@@ -26,16 +37,17 @@ use itertools::Itertools;
 use rayon::prelude::*;
 
 #[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PolynomialInfo {
-    label: PolynomialLabel,
+    pub(super) label: PolynomialLabel,
     degree_bound: Option<usize>,
     hiding_bound: Option<usize>,
 }
 
 impl PolynomialInfo {
     /// Construct a new labeled polynomial by consuming `polynomial`.
-    pub fn new(label: PolynomialLabel, degree_bound: Option<usize>, hiding_bound: Option<usize>) -> Self {
-        Self { label, degree_bound, hiding_bound }
+    pub fn new(label: impl Into<PolynomialLabel>, degree_bound: Option<usize>, hiding_bound: Option<usize>) -> Self {
+        Self { label: label.into(), degree_bound, hiding_bound }
     }
 
     /// Return the label for `self`.
@@ -43,6 +55,11 @@ impl PolynomialInfo {
         &self.label
     }
 
+    /// Return the label for `self` as a [`PolynomialLabel`], which can be cloned without allocating.
+    pub fn polynomial_label(&self) -> &PolynomialLabel {
+        &self.label
+    }
+
     /// Retrieve the degree bound in `self`.
     pub fn degree_bound(&self) -> Option<usize> {
         self.degree_bound
@@ -62,12 +79,51 @@ impl PolynomialInfo {
 /// A polynomial along with information about its degree bound (if any), and the
 /// maximum number of queries that will be made to it. This latter number determines
 /// the amount of protection that will be provided to a commitment for this polynomial.
-#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize, PartialEq, Eq)]
+///
+/// With the `serde` feature, `self` can also be serialized with `serde`, e.g. to JSON for inspection.
+/// This format is independent of the canonical one used in proofs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(serialize = "", deserialize = "F: FftField"))
+)]
 pub struct LabeledPolynomial<F: Field> {
     pub info: PolynomialInfo,
     pub polynomial: Polynomial<'static, F>,
 }
 
+impl<F: Field> CanonicalSerialize for LabeledPolynomial<F> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.info.serialize_with_mode(&mut writer, compress)?;
+        self.polynomial.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.info.serialized_size(compress) + self.polynomial.serialized_size(compress)
+    }
+}
+
+impl<F: FftField> Valid for LabeledPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.info.check()?;
+        self.polynomial.check()
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for LabeledPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            info: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            polynomial: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
 impl<F: Field> core::ops::Deref for LabeledPolynomial<F> {
     type Target = Polynomial<'static, F>;
 
@@ -79,7 +135,7 @@ impl<F: Field> core::ops::Deref for LabeledPolynomial<F> {
 impl<F: Field> LabeledPolynomial<F> {
     /// Construct a new labeled polynomial by consuming `polynomial`.
     pub fn new(
-        label: PolynomialLabel,
+        label: impl Into<PolynomialLabel>,
         polynomial: impl Into<Polynomial<'static, F>>,
         degree_bound: Option<usize>,
         hiding_bound: Option<usize>,
@@ -88,6 +144,12 @@ impl<F: Field> LabeledPolynomial<F> {
         Self { info, polynomial: polynomial.into() }
     }
 
+    /// Construct a new labeled polynomial for `polynomial` with the label, degree bound, and hiding bound
+    /// of `template`.
+    pub fn with_metadata_from(template: &Self, polynomial: impl Into<Polynomial<'static, F>>) -> Self {
+        Self { info: template.info.clone(), polynomial: polynomial.into() }
+    }
+
     pub fn info(&self) -> &PolynomialInfo {
         &self.info
     }
@@ -97,6 +159,11 @@ impl<F: Field> LabeledPolynomial<F> {
         &self.info.label
     }
 
+    /// Return the label for `self` as a [`PolynomialLabel`], which can be cloned without allocating.
+    pub fn polynomial_label(&self) -> &PolynomialLabel {
+        &self.info.label
+    }
+
     /// Retrieve the polynomial from `self`.
     pub fn polynomial(&self) -> &Polynomial<F> {
         &self.polynomial
@@ -107,16 +174,140 @@ impl<F: Field> LabeledPolynomial<F> {
         &mut self.polynomial
     }
 
+    /// Returns the coefficient of `x^degree` in the enclosed polynomial.
+    pub fn coefficient(&self, degree: usize) -> F {
+        self.polynomial.coefficient(degree)
+    }
+
+    /// Set the coefficient of `x^degree` in the enclosed polynomial to `value`.
+    /// Dense polynomials grow or shrink as needed, and sparse polynomials gain or lose a term.
+    pub fn set_coefficient(&mut self, degree: usize, value: F) {
+        self.polynomial.set_coefficient(degree, value)
+    }
+
+    /// Consume `self` and return the enclosed polynomial in dense form.
+    pub fn into_dense(self) -> DensePolynomial<F> {
+        self.polynomial.into()
+    }
+
+    /// Consume `self` and return its label, polynomial, degree bound, and hiding bound.
+    pub fn into_parts(self) -> (PolynomialLabel, Polynomial<'static, F>, Option<usize>, Option<usize>) {
+        let PolynomialInfo { label, degree_bound, hiding_bound } = self.info;
+        (label, self.polynomial, degree_bound, hiding_bound)
+    }
+
     /// Evaluate the polynomial in `self`.
     pub fn evaluate(&self, point: F) -> F {
         self.polynomial.evaluate(point)
     }
 
+    /// Checks if the polynomial in `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.polynomial.is_zero()
+    }
+
+    /// Checks if the polynomial in `self` is the constant `1`.
+    pub fn is_one(&self) -> bool {
+        self.polynomial.is_one()
+    }
+
+    /// Checks if the polynomial in `self` is constant (including zero).
+    pub fn is_constant(&self) -> bool {
+        self.polynomial.is_constant()
+    }
+
+    /// Checks if the polynomial in `self` is stored in dense form.
+    pub fn is_dense(&self) -> bool {
+        self.polynomial.is_dense()
+    }
+
+    /// Checks if the polynomial in `self` is stored in sparse form.
+    pub fn is_sparse(&self) -> bool {
+        self.polynomial.is_sparse()
+    }
+
+    /// Returns the number of coefficients stored by the polynomial in `self`.
+    /// See [`Polynomial::num_stored_coefficients`].
+    pub fn num_stored_coefficients(&self) -> usize {
+        self.polynomial.num_stored_coefficients()
+    }
+
+    /// Feed the content of `self` into `hasher`, for use as a key in caches of commitments.
+    ///
+    /// This hashes the nonzero terms of the polynomial together with the degree and hiding bounds,
+    /// but not the label, so equal polynomials with different labels hash equally. Sparse and dense
+    /// representations of the same polynomial also hash equally, regardless of trailing zeros.
+    ///
+    /// This is not a cryptographic commitment: collisions are possible, so equal hashes must be
+    /// confirmed by comparing the polynomials themselves.
+    pub fn content_hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        use core::hash::Hash;
+
+        for (degree, coeff) in self.polynomial.iter_nonzero_terms() {
+            degree.hash(hasher);
+            coeff.hash(hasher);
+        }
+        self.degree_bound().hash(hasher);
+        self.hiding_bound().hash(hasher);
+    }
+
+    /// Evaluate the polynomial in `self`, returning an error if it is not in canonical form.
+    pub fn try_evaluate(&self, point: F) -> Result<F, PCError> {
+        if !self.polynomial.is_canonical() {
+            return Err(PCError::NonCanonicalPolynomial { label: self.label().to_string() });
+        }
+        Ok(self.polynomial.evaluate(point))
+    }
+
     /// Retrieve the degree bound in `self`.
     pub fn degree_bound(&self) -> Option<usize> {
         self.info.degree_bound
     }
 
+    /// Return the actual degree of the polynomial in `self`.
+    /// In debug builds, this also checks that the degree does not exceed the degree bound, if there is one.
+    pub fn effective_degree(&self) -> usize {
+        if cfg!(debug_assertions) {
+            if let Err(error) = self.check_degree_bound() {
+                panic!("{error}");
+            }
+        }
+        self.polynomial.degree()
+    }
+
+    /// Check that the degree of the polynomial in `self` does not exceed its degree bound, if there is one.
+    pub fn check_degree_bound(&self) -> Result<(), PolyCommitError> {
+        let degree = self.polynomial.degree();
+        match self.degree_bound() {
+            Some(degree_bound) if degree > degree_bound => {
+                Err(PolyCommitError::DegreeBoundExceeded { label: self.label().to_string(), degree, degree_bound })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Return the degree bound that is enforced for `self` when committing, if any.
+    pub fn enforced_degree(&self) -> Option<usize> {
+        self.degree_bound()
+    }
+
+    /// Return `x^(max_degree - degree_bound) * p` if `self` has a degree bound, and `p` otherwise,
+    /// where `p` is the polynomial in `self`.
+    /// Returns an error if the degree bound is greater than `max_degree`.
+    pub fn shifted_for_degree_bound(&self, max_degree: usize) -> Result<DensePolynomial<F>, PCError> {
+        let polynomial = self.polynomial.to_dense().into_owned();
+        match self.degree_bound() {
+            Some(degree_bound) if degree_bound > max_degree => Err(PCError::IncorrectDegreeBound {
+                poly_degree: self.polynomial.degree(),
+                degree_bound,
+                max_degree,
+                label: self.label().to_string(),
+            }),
+            Some(degree_bound) => Ok(polynomial.mul_by_xn(max_degree - degree_bound)),
+            None => Ok(polynomial),
+        }
+    }
+
     /// Retrieve whether the polynomial in `self` should be hidden.
     pub fn is_hiding(&self) -> bool {
         self.info.hiding_bound.is_some()
@@ -126,6 +317,98 @@ impl<F: Field> LabeledPolynomial<F> {
     pub fn hiding_bound(&self) -> Option<usize> {
         self.info.hiding_bound
     }
+
+    /// Return the polynomial in `self`, along with the masking polynomial of its commitment if `self` is hiding.
+    ///
+    /// A hiding commitment to `self` is blinded by the polynomial in the `randomness` returned alongside it by
+    /// [`SonicKZG10::commit`](super::SonicKZG10::commit), so the polynomial can be revealed while the mask
+    /// stays secret.
+    pub fn split_hiding<E: PairingEngine<Fr = F>>(
+        &self,
+        randomness: &Randomness<E>,
+    ) -> (DensePolynomial<F>, Option<DensePolynomial<F>>) {
+        let mask = self.is_hiding().then(|| randomness.blinding_polynomial.clone());
+        (self.polynomial.to_dense().into_owned(), mask)
+    }
+
+    /// If `self` is hiding, overwrite its coefficients with zeros, so that any secret randomness
+    /// is wiped from memory. See [`DensePolynomial::secure_clear`] and [`SparsePolynomial::secure_clear`].
+    pub fn secure_clear(&mut self) {
+        if self.is_hiding() {
+            self.polynomial.secure_clear();
+        }
+    }
+
+    /// Pack `polynomials` into a single polynomial, placing the coefficients of `polynomials[i]`
+    /// at degrees `i * stride` to `(i + 1) * stride - 1`, i.e. computing `sum_i x^(i * stride) * polynomials[i]`.
+    /// The packed polynomials can be recovered with [`Self::unpack`].
+    ///
+    /// Each polynomial must have degree less than `stride`, so that it does not overlap with the next one.
+    /// Returns an error otherwise, or if the packed polynomial would have more than `usize::MAX` coefficients.
+    pub fn concat_along_degree(polynomials: &[Self], stride: usize) -> Result<DensePolynomial<F>, PolyCommitError> {
+        let count = polynomials.len();
+        let len = count.checked_mul(stride).ok_or(PolyCommitError::PackedLengthOverflow { count, stride })?;
+        let mut coeffs = vec![F::zero(); len];
+        for (i, p) in polynomials.iter().enumerate() {
+            let degree = p.degree();
+            if degree >= stride {
+                return Err(PolyCommitError::StrideExceeded { label: p.label().to_string(), degree, stride });
+            }
+            for (j, coeff) in p.polynomial.iter_nonzero_terms() {
+                coeffs[i * stride + j] = coeff;
+            }
+        }
+        Ok(DensePolynomial::from_coefficients_vec(coeffs))
+    }
+
+    /// Split a polynomial produced by [`Self::concat_along_degree`] back into the `count` polynomials
+    /// that were packed into it with the given `stride`.
+    /// Coefficients of degree `count * stride` or more are ignored.
+    pub fn unpack(packed: &DensePolynomial<F>, stride: usize, count: usize) -> Vec<DensePolynomial<F>> {
+        (0..count)
+            .map(|i| {
+                let start = i.saturating_mul(stride).min(packed.coeffs.len());
+                let end = (i + 1).saturating_mul(stride).min(packed.coeffs.len());
+                DensePolynomial::from_coefficients_slice(&packed.coeffs[start..end])
+            })
+            .collect()
+    }
+}
+
+impl<F: PrimeField> LabeledPolynomial<F> {
+    /// Evaluate the polynomial in `self` over `domain`.
+    /// Returns an error if the polynomial is not uniquely determined by its evaluations over `domain`.
+    pub fn as_evaluations(&self, domain: EvaluationDomain<F>) -> Result<EvaluationsOnDomain<F>, PCError> {
+        let poly_degree = self.polynomial.degree();
+        if poly_degree >= domain.size() {
+            return Err(PCError::PolynomialDegreeTooLargeForDomain {
+                label: self.label().to_string(),
+                poly_degree,
+                domain_size: domain.size(),
+            });
+        }
+        let polynomial = match &self.polynomial {
+            Polynomial::Dense(p) => Polynomial::from(p.as_ref()),
+            Polynomial::Sparse(p) => Polynomial::from(p.as_ref()),
+        };
+        Ok(Polynomial::evaluate_over_domain(polynomial, domain))
+    }
+}
+
+impl<F: PrimeField> EvaluationsOnDomain<F> {
+    /// Interpolate `self` and wrap the result in a [`LabeledPolynomial`] with the given bounds.
+    /// This is the inverse of [`LabeledPolynomial::as_evaluations`].
+    /// Returns an error if the degree of the interpolated polynomial exceeds `degree_bound`.
+    pub fn into_labeled_polynomial(
+        self,
+        label: impl Into<PolynomialLabel>,
+        degree_bound: Option<usize>,
+        hiding_bound: Option<usize>,
+    ) -> Result<LabeledPolynomial<F>, PCError> {
+        let polynomial = LabeledPolynomial::new(label, self.interpolate(), degree_bound, hiding_bound);
+        polynomial.check_degree_bound()?;
+        Ok(polynomial)
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////////
@@ -139,10 +422,21 @@ pub struct LabeledPolynomialWithBasis<'a, F: PrimeField> {
     pub polynomial: Vec<(F, PolynomialWithBasis<'a, F>)>,
 }
 
+/// The number of terms in each of the groups that [`LabeledPolynomialWithBasis::sum`] combines separately.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupSummary {
+    /// The number of Lagrange-basis terms, for each domain size.
+    pub lagrange_terms: BTreeMap<usize, usize>,
+    /// The number of dense monomial-basis terms, for each degree bound.
+    pub dense_terms: BTreeMap<Option<usize>, usize>,
+    /// The number of sparse monomial-basis terms, which are all combined into one polynomial.
+    pub sparse_terms: usize,
+}
+
 impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
     /// Construct a new labeled polynomial by consuming `polynomial`.
     pub fn new_monomial_basis(
-        label: PolynomialLabel,
+        label: impl Into<PolynomialLabel>,
         polynomial: &'a Polynomial<F>,
         degree_bound: Option<usize>,
         hiding_bound: Option<usize>,
@@ -154,7 +448,7 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
 
     /// Construct a new labeled polynomial by consuming `polynomial`.
     pub fn new_linear_combination(
-        label: PolynomialLabel,
+        label: impl Into<PolynomialLabel>,
         polynomial: Vec<(F, PolynomialWithBasis<'a, F>)>,
         hiding_bound: Option<usize>,
     ) -> Self {
@@ -162,8 +456,33 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
         Self { info, polynomial }
     }
 
+    /// Construct a new labeled polynomial by consuming `polynomial`, checking that
+    /// `polynomial` has at least one term, and that `hiding_bound` is nonzero and
+    /// small enough to commit to with powers up to `max_degree`.
+    pub fn try_new_linear_combination(
+        label: impl Into<PolynomialLabel>,
+        polynomial: Vec<(F, PolynomialWithBasis<'a, F>)>,
+        hiding_bound: Option<usize>,
+        max_degree: usize,
+    ) -> Result<Self, PCError> {
+        let label = label.into();
+        if polynomial.is_empty() {
+            return Err(PCError::EmptyLinearCombination(label.to_string()));
+        }
+        if let Some(hiding_poly_degree) = hiding_bound {
+            let num_powers = max_degree + 1;
+            if hiding_poly_degree == 0 {
+                return Err(PCError::HidingBoundIsZero);
+            } else if hiding_poly_degree >= num_powers {
+                // Committing to a hiding poly of degree `hiding_poly_degree` needs `hiding_poly_degree + 1` powers.
+                return Err(PCError::HidingBoundToolarge { hiding_poly_degree, num_powers });
+            }
+        }
+        Ok(Self::new_linear_combination(label, polynomial, hiding_bound))
+    }
+
     pub fn new_lagrange_basis(
-        label: PolynomialLabel,
+        label: impl Into<PolynomialLabel>,
         polynomial: EvaluationsOnDomain<F>,
         hiding_bound: Option<usize>,
     ) -> Self {
@@ -173,7 +492,7 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
     }
 
     pub fn new_lagrange_basis_ref(
-        label: PolynomialLabel,
+        label: impl Into<PolynomialLabel>,
         polynomial: &'a EvaluationsOnDomain<F>,
         hiding_bound: Option<usize>,
     ) -> Self {
@@ -187,6 +506,12 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
         &self.info.label
     }
 
+    /// Convert `self` into a linear combination that owns all of its terms, cloning any borrowed ones.
+    pub fn into_owned(self) -> LabeledPolynomialWithBasis<'static, F> {
+        let polynomial = self.polynomial.into_iter().map(|(c, p)| (c, p.into_owned())).collect();
+        LabeledPolynomialWithBasis { info: self.info, polynomial }
+    }
+
     /// Return the information about the label, degree bound, and hiding bound of `self`.
     pub fn info(&self) -> &PolynomialInfo {
         &self.info
@@ -198,6 +523,7 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
             .map(|(_, p)| match p {
                 PolynomialWithBasis::Lagrange { evaluations } => evaluations.domain().size() - 1,
                 PolynomialWithBasis::Monomial { polynomial, .. } => polynomial.degree(),
+                PolynomialWithBasis::Power { degree, .. } => *degree,
             })
             .max()
             .unwrap_or(0)
@@ -210,37 +536,71 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
 
     /// Compute a linear combination of the terms in `self.polynomial`, producing an iterator
     /// over polynomials of the same time.
+    ///
+    /// When `self` has a hiding bound, each dense polynomial in the output has
+    /// `max(len, hiding_bound + 1)` coefficients, where `len` is the largest number of coefficients
+    /// among the dense terms sharing its degree bound. This leaves room to add `hiding_bound + 1`
+    /// masking coefficients in place, so the padded polynomial may have trailing zeros until then.
     pub fn sum(&self) -> impl Iterator<Item = PolynomialWithBasis<'a, F>> {
+        self.sum_with_options(false)
+    }
+
+    /// Compute a linear combination of the terms in `self.polynomial` as in [`Self::sum`], except that all
+    /// Lagrange terms are first resampled to the largest of their domains (see [`PolynomialWithBasis::resample_to`]),
+    /// so that they are combined into a single polynomial in Lagrange basis.
+    ///
+    /// Resampling costs an IFFT and an FFT for each term that is not over the largest domain.
+    pub fn sum_with_resampling(&self) -> impl Iterator<Item = PolynomialWithBasis<'a, F>> {
+        self.sum_with_options(true)
+    }
+
+    fn sum_with_options(&self, resample: bool) -> vec::IntoIter<PolynomialWithBasis<'a, F>> {
         if self.polynomial.len() == 1 && self.polynomial[0].0.is_one() {
-            vec![self.polynomial[0].1.clone()].into_iter()
+            let mut p = self.polynomial[0].1.clone();
+            // Only clone a borrowed dense polynomial if it has to be padded.
+            if let PolynomialWithBasis::Monomial { polynomial, .. } = &mut p {
+                if polynomial.as_dense().is_some_and(|p| self.needs_hiding_padding(p)) {
+                    if let Some(p) = polynomial.to_mut().as_dense_mut() {
+                        self.pad_for_hiding(p);
+                    }
+                }
+            }
+            vec![p].into_iter()
         } else {
             use PolynomialWithBasis::*;
             let mut lagrange_polys = HashMap::<usize, Vec<_>>::new();
             let mut dense_polys = HashMap::<_, DensePolynomial<F>>::new();
             let mut sparse_poly = SparsePolynomial::zero();
+            let target_domain = match resample {
+                true => self.polynomial.iter().filter_map(|(_, p)| p.domain()).max_by_key(|d| d.size()),
+                false => None,
+            };
             // We have sets of polynomials divided along three critera:
             // 1. All `Lagrange` polynomials are in the set corresponding to their domain.
             // 2. All `Dense` polynomials are in the set corresponding to their degree bound.
             // 3. All `Sparse` polynomials are in the set corresponding to their degree bound.
+            // `Power` terms are added directly to the sparse polynomial, since they have no degree bound.
             for (c, poly) in self.polynomial.iter() {
-                match poly {
+                let poly = match (target_domain, poly.domain()) {
+                    (Some(target), Some(domain)) if target.size() != domain.size() => {
+                        Cow::Owned(poly.resample_to(target))
+                    }
+                    _ => Cow::Borrowed(poly),
+                };
+                match poly.as_ref() {
                     Monomial { polynomial, degree_bound } => {
                         use Polynomial::*;
                         match polynomial.as_ref() {
                             Dense(p) => {
-                                if let Some(e) = dense_polys.get_mut(degree_bound) {
-                                    // Zip safety: `p` could be of smaller degree than `e` (or vice versa),
-                                    // so it's okay to just use `zip` here.
-                                    cfg_iter_mut!(e).zip(&p.coeffs).for_each(|(e, f)| *e += *c * f)
-                                } else {
-                                    let mut e: DensePolynomial<F> = p.clone().into_owned();
-                                    cfg_iter_mut!(e).for_each(|e| *e *= c);
-                                    dense_polys.insert(degree_bound, e);
-                                }
+                                dense_polys.entry(*degree_bound).or_insert_with(DensePolynomial::zero).add_scaled(*c, p)
                             }
                             Sparse(p) => sparse_poly += (*c, p.as_ref()),
                         }
                     }
+                    Power { degree, coeff } => {
+                        let coeff = sparse_poly.coefficient(*degree) + *c * coeff;
+                        sparse_poly.set_coefficient(*degree, coeff);
+                    }
                     Lagrange { evaluations } => {
                         let domain = evaluations.domain().size();
                         if let Some(e) = lagrange_polys.get_mut(&domain) {
@@ -253,18 +613,19 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
                     }
                 }
             }
+            dense_polys.values_mut().for_each(|p| self.pad_for_hiding(p));
             let sparse_poly = Polynomial::from(sparse_poly);
             let sparse_poly = Monomial { polynomial: Cow::Owned(sparse_poly), degree_bound: None };
             lagrange_polys
                 .into_iter()
                 .map(|(k, v)| {
-                    let domain = EvaluationDomain::new(k).unwrap();
+                    let domain = EvaluationDomain::new(k).expect("the evaluations are over a domain of size `k`");
                     Lagrange { evaluations: Cow::Owned(EvaluationsOnDomain::from_vec_and_domain(v, domain)) }
                 })
                 .chain({
                     dense_polys
                         .into_iter()
-                        .map(|(degree_bound, p)| PolynomialWithBasis::new_dense_monomial_basis(p, *degree_bound))
+                        .map(|(degree_bound, p)| PolynomialWithBasis::new_dense_monomial_basis(p, degree_bound))
                 })
                 .chain([sparse_poly])
                 .collect::<Vec<_>>()
@@ -272,6 +633,37 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
         }
     }
 
+    /// Returns whether `p` has fewer than `hiding_bound + 1` coefficients, when `self` has a hiding bound.
+    fn needs_hiding_padding(&self, p: &DensePolynomial<F>) -> bool {
+        self.hiding_bound().is_some_and(|hiding_bound| p.coeffs.len() <= hiding_bound)
+    }
+
+    /// Pad `p` with zeros to `hiding_bound + 1` coefficients, if it is shorter and `self` has a hiding bound.
+    fn pad_for_hiding(&self, p: &mut DensePolynomial<F>) {
+        if let Some(hiding_bound) = self.hiding_bound().filter(|_| self.needs_hiding_padding(p)) {
+            p.coeffs.resize(hiding_bound + 1, F::zero());
+        }
+    }
+
+    /// Count the terms of `self` in each of the groups formed by [`Self::sum`], without combining them.
+    /// Note that `sum` returns a single term with coefficient one unchanged, instead of grouping it.
+    pub fn group_summary(&self) -> GroupSummary {
+        let mut summary = GroupSummary::default();
+        for (_, poly) in &self.polynomial {
+            match poly {
+                PolynomialWithBasis::Monomial { polynomial, degree_bound } => match polynomial.as_ref() {
+                    Polynomial::Dense(_) => *summary.dense_terms.entry(*degree_bound).or_default() += 1,
+                    Polynomial::Sparse(_) => summary.sparse_terms += 1,
+                },
+                PolynomialWithBasis::Power { .. } => summary.sparse_terms += 1,
+                PolynomialWithBasis::Lagrange { evaluations } => {
+                    *summary.lagrange_terms.entry(evaluations.domain().size()).or_default() += 1
+                }
+            }
+        }
+        summary
+    }
+
     /// Retrieve the degree bound in `self`.
     pub fn degree_bound(&self) -> Option<usize> {
         self.polynomial
@@ -283,6 +675,13 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
             .max()
     }
 
+    /// Iterate over the distinct degree bounds of the terms in `self`, in ascending order.
+    /// These are the degree bounds by which [`Self::sum`] groups the monomial terms, and hence
+    /// the degree bounds for which shifted commitments are needed.
+    pub fn degree_bounds(&self) -> impl Iterator<Item = usize> {
+        self.polynomial.iter().filter_map(|(_, p)| p.degree_bound()).collect::<BTreeSet<_>>().into_iter()
+    }
+
     /// Retrieve whether the polynomial in `self` should be hidden.
     pub fn is_hiding(&self) -> bool {
         self.info.hiding_bound.is_some()
@@ -292,6 +691,203 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
     pub fn hiding_bound(&self) -> Option<usize> {
         self.info.hiding_bound
     }
+
+    /// Bring the terms of `self.polynomial` into a canonical form, so that equivalent
+    /// linear combinations serialize (and hash) identically:
+    /// 1. terms with a zero scalar are removed,
+    /// 2. identical terms (the same monomial polynomial with the same degree bound, or the same
+    ///    Lagrange evaluations) are merged by adding their scalars, dropping the result if it is zero,
+    /// 3. the remaining terms are sorted by their basis polynomial. Monomial terms come first, ordered by
+    ///    degree bound and then by their nonzero coefficients `(i, a_i)` compared lexicographically;
+    ///    Lagrange terms follow, ordered by domain size and then by their evaluations.
+    /// 4. monomial polynomials are brought into canonical form (see [`Polynomial::is_canonical`]),
+    ///    so that the kept representation of a merged term does not depend on which term was kept.
+    pub fn canonicalize(&mut self) {
+        let mut terms = BTreeMap::new();
+        for (c, p) in self.polynomial.drain(..).filter(|(c, _)| !c.is_zero()) {
+            terms.entry(p.canonical_key()).and_modify(|(sum, _)| *sum += c).or_insert((c, p));
+        }
+        self.polynomial =
+            terms.into_values().filter(|(c, _)| !c.is_zero()).map(|(c, p)| (c, p.into_canonical())).collect();
+    }
+}
+
+impl<'a, F: PrimeField> CanonicalSerialize for LabeledPolynomialWithBasis<'a, F> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.info.serialize_with_mode(&mut writer, compress)?;
+        self.polynomial.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.info.serialized_size(compress) + self.polynomial.serialized_size(compress)
+    }
+}
+
+impl<'a, F: PrimeField> Valid for LabeledPolynomialWithBasis<'a, F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.info.check()?;
+        self.polynomial.check()
+    }
+}
+
+impl<'a, F: PrimeField> CanonicalDeserialize for LabeledPolynomialWithBasis<'a, F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            info: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            polynomial: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+/// Construct the linear combination, labelled `label`, of the polynomials in `polys`
+/// named by `terms`. The hiding bound of the result is the largest hiding bound of its terms.
+/// Returns an error if a label in `terms` does not appear in `polys`.
+pub fn linear_combination<'a, F: PrimeField>(
+    label: impl Into<PolynomialLabel>,
+    polys: &'a HashMap<PolynomialLabel, LabeledPolynomial<F>>,
+    terms: &[(PolynomialLabel, F)],
+) -> Result<LabeledPolynomialWithBasis<'a, F>, PCError> {
+    let mut hiding_bound = None;
+    let polynomial = terms
+        .iter()
+        .map(|(term_label, coeff)| {
+            let p =
+                polys.get(term_label).ok_or_else(|| PCError::MissingPolynomial { label: term_label.to_string() })?;
+            // Some(_) > None, always.
+            hiding_bound = core::cmp::max(hiding_bound, p.hiding_bound());
+            let p = PolynomialWithBasis::Monomial {
+                polynomial: Cow::Borrowed(p.polynomial()),
+                degree_bound: p.degree_bound(),
+            };
+            Ok((*coeff, p))
+        })
+        .collect::<Result<Vec<_>, PCError>>()?;
+    Ok(LabeledPolynomialWithBasis::new_linear_combination(label, polynomial, hiding_bound))
+}
+
+/// Evaluate each of `evals` at the point `z` by the barycentric formula, without interpolating them.
+///
+/// For a domain `H = {g^i}` of size `n`, the evaluation of `f` at `z` is
+/// `(z^n - 1) / n * sum_i f(g^i) * g^i / (z - g^i)`. The Lagrange coefficients at `z` are computed once
+/// for each distinct domain among `evals`, with the denominators `z - g^i` of all domains inverted by a
+/// single batch inversion, and are then shared by all of the evaluations over that domain.
+/// The results match [`PolynomialWithBasis::evaluate`] for each polynomial in Lagrange basis.
+///
+/// Panics if the number of evaluations in one of `evals` does not match the size of its domain.
+pub fn barycentric_batch_evaluate<F: PrimeField>(evals: &[&EvaluationsOnDomain<F>], z: F) -> Vec<F> {
+    // Domains are determined by their size, so the Lagrange coefficients are keyed by size.
+    let domains = evals.iter().map(|e| (e.domain().size(), e.domain())).collect::<BTreeMap<_, _>>();
+
+    let mut coefficients = BTreeMap::new();
+    let mut offsets = Vec::with_capacity(domains.len());
+    let mut denominators = Vec::new();
+    for (size, domain) in domains {
+        if domain.evaluate_vanishing_polynomial(z).is_zero() {
+            // `z` is an element of the domain, so exactly one Lagrange coefficient is nonzero.
+            coefficients.insert(size, domain.evaluate_all_lagrange_coefficients(z));
+        } else {
+            offsets.push((size, domain, denominators.len()));
+            denominators.extend(domain.elements().map(|element| z - element));
+        }
+    }
+    snarkvm_fields::batch_inversion(&mut denominators);
+
+    for (size, domain, offset) in offsets {
+        let multiplier = domain.evaluate_vanishing_polynomial(z) * domain.size_inv;
+        let lagrange_coefficients = cfg_iter!(denominators[offset..offset + size])
+            .zip_eq(domain.elements().collect::<Vec<_>>())
+            .map(|(inverse, element)| multiplier * inverse * element)
+            .collect::<Vec<_>>();
+        coefficients.insert(size, lagrange_coefficients);
+    }
+
+    evals
+        .iter()
+        .map(|e| {
+            let lagrange_coefficients = &coefficients[&e.domain().size()];
+            assert_eq!(
+                e.evaluations.len(),
+                lagrange_coefficients.len(),
+                "the number of evaluations must match the domain size"
+            );
+            e.evaluate_with_coeffs(lagrange_coefficients)
+        })
+        .collect()
+}
+
+/// A collection of labeled polynomials, keyed by their labels.
+/// Dereferences to the underlying map, so it can be passed wherever a
+/// `&HashMap<PolynomialLabel, LabeledPolynomial<F>>` is expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialMap<F: Field>(HashMap<PolynomialLabel, LabeledPolynomial<F>>);
+
+impl<F: Field> Default for PolynomialMap<F> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<F: Field> PolynomialMap<F> {
+    /// Construct an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert `polynomial`, keyed by its label.
+    /// Returns an error, and leaves `self` unchanged, if a polynomial with the same label is already present.
+    pub fn insert_labeled(&mut self, polynomial: LabeledPolynomial<F>) -> Result<(), PCError> {
+        match self.0.entry(polynomial.label().into()) {
+            Entry::Occupied(entry) => Err(PCError::DuplicatePolynomial { label: entry.key().to_string() }),
+            Entry::Vacant(entry) => {
+                entry.insert(polynomial);
+                Ok(())
+            }
+        }
+    }
+
+    /// Return the polynomial labelled `label`, or an error if there is none.
+    pub fn get_or_err(&self, label: &str) -> Result<&LabeledPolynomial<F>, PCError> {
+        self.0.get(label).ok_or_else(|| PCError::MissingPolynomial { label: label.to_string() })
+    }
+
+    /// Iterate over the polynomials labelled by `labels`, in the order of `labels`.
+    /// Yields an error for each label that has no polynomial.
+    pub fn iter_in_order<'a>(
+        &'a self,
+        labels: &'a [&str],
+    ) -> impl Iterator<Item = Result<&'a LabeledPolynomial<F>, PCError>> + 'a {
+        labels.iter().map(|label| self.get_or_err(label))
+    }
+
+    /// Return the underlying map.
+    pub fn into_inner(self) -> HashMap<PolynomialLabel, LabeledPolynomial<F>> {
+        self.0
+    }
+}
+
+impl<F: Field> core::ops::Deref for PolynomialMap<F> {
+    type Target = HashMap<PolynomialLabel, LabeledPolynomial<F>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<F: Field> TryFrom<Vec<LabeledPolynomial<F>>> for PolynomialMap<F> {
+    type Error = PCError;
+
+    /// Collect `polynomials` into a map, returning an error if any label appears more than once.
+    fn try_from(polynomials: Vec<LabeledPolynomial<F>>) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        for polynomial in polynomials {
+            map.insert_labeled(polynomial)?;
+        }
+        Ok(map)
+    }
 }
 
 impl<'a, F: PrimeField> From<&'a LabeledPolynomial<F>> for LabeledPolynomialWithBasis<'a, F> {
@@ -314,6 +910,23 @@ impl<'a, F: PrimeField> From<LabeledPolynomial<F>> for LabeledPolynomialWithBasi
     }
 }
 
+/// Buffers that are reused by [`PolynomialWithBasis::evaluate_with_scratch`], so that evaluating
+/// polynomials in Lagrange basis does not allocate once the buffers are large enough.
+#[derive(Clone, Debug, Default)]
+pub struct LagrangeEvalScratch<F: Field> {
+    /// The elements of the domain used in the last evaluation, i.e. the powers of its generator.
+    powers: Vec<F>,
+    /// The running products of the denominators `point - g^i`.
+    denominators: Vec<F>,
+}
+
+impl<F: Field> LagrangeEvalScratch<F> {
+    /// Construct empty buffers, which grow as needed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum PolynomialWithBasis<'a, F: PrimeField> {
     /// A polynomial in monomial basis, along with information about
@@ -323,9 +936,125 @@ pub enum PolynomialWithBasis<'a, F: PrimeField> {
     /// A polynomial in Lagrange basis, along with information about
     /// its degree bound (if any).
     Lagrange { evaluations: Cow<'a, EvaluationsOnDomain<F>> },
+
+    /// The monomial `coeff * x^degree`, without a degree bound.
+    /// This avoids allocating a sparse polynomial for a single term.
+    Power { degree: usize, coeff: F },
+}
+
+impl<'a, F: PrimeField> CanonicalSerialize for PolynomialWithBasis<'a, F> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.is_in_monomial_basis().serialize_with_mode(&mut writer, compress)?;
+        match self {
+            Self::Monomial { polynomial, degree_bound } => {
+                polynomial.serialize_with_mode(&mut writer, compress)?;
+                degree_bound.serialize_with_mode(&mut writer, compress)
+            }
+            Self::Lagrange { evaluations } => evaluations.serialize_with_mode(&mut writer, compress),
+            // A power is serialized as the equivalent sparse polynomial.
+            Self::Power { degree, coeff } => {
+                let polynomial = Polynomial::from(SparsePolynomial::from_coefficients([(*degree, *coeff)]));
+                polynomial.serialize_with_mode(&mut writer, compress)?;
+                None::<usize>.serialize_with_mode(&mut writer, compress)
+            }
+        }
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        let inner_size = match self {
+            Self::Monomial { polynomial, degree_bound } => {
+                polynomial.serialized_size(compress) + degree_bound.serialized_size(compress)
+            }
+            Self::Lagrange { evaluations } => evaluations.serialized_size(compress),
+            Self::Power { degree, coeff } => {
+                let polynomial = Polynomial::from(SparsePolynomial::from_coefficients([(*degree, *coeff)]));
+                polynomial.serialized_size(compress) + None::<usize>.serialized_size(compress)
+            }
+        };
+        true.serialized_size(compress) + inner_size
+    }
+}
+
+impl<'a, F: PrimeField> Valid for PolynomialWithBasis<'a, F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        match self {
+            Self::Monomial { polynomial, .. } => polynomial.check(),
+            Self::Lagrange { evaluations } => evaluations.check(),
+            Self::Power { coeff, .. } => coeff.check(),
+        }
+    }
+}
+
+impl<'a, F: PrimeField> CanonicalDeserialize for PolynomialWithBasis<'a, F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let is_in_monomial_basis = bool::deserialize_with_mode(&mut reader, compress, validate)?;
+        if is_in_monomial_basis {
+            let polynomial = Polynomial::deserialize_with_mode(&mut reader, compress, validate)?;
+            let degree_bound = Option::<usize>::deserialize_with_mode(&mut reader, compress, validate)?;
+            Ok(Self::new_monomial_basis(polynomial, degree_bound))
+        } else {
+            let evaluations = EvaluationsOnDomain::deserialize_with_mode(&mut reader, compress, validate)?;
+            Ok(Self::new_lagrange_basis(evaluations))
+        }
+    }
 }
 
 impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
+    /// Return `self` with its monomial polynomial, if any, in canonical form, without trailing zeros or zero terms.
+    /// Terms with the same [`Self::canonical_key`] then serialize identically.
+    pub(crate) fn into_canonical(self) -> Self {
+        match self {
+            Self::Monomial { polynomial, degree_bound } if !polynomial.is_canonical() => {
+                let polynomial = match polynomial.into_owned() {
+                    Polynomial::Sparse(p) => {
+                        Polynomial::from(SparsePolynomial::from_coefficients(p.coeffs().map(|(i, c)| (*i, *c))))
+                    }
+                    Polynomial::Dense(p) => {
+                        Polynomial::from(DensePolynomial::from_coefficients_vec(p.into_owned().coeffs))
+                    }
+                };
+                Self::Monomial { polynomial: Cow::Owned(polynomial), degree_bound }
+            }
+            p => p,
+        }
+    }
+
+    /// The key used to identify and order terms in [`LabeledPolynomialWithBasis::canonicalize`].
+    fn canonical_key(&self) -> (bool, Option<usize>, usize, Vec<(usize, F)>) {
+        match self {
+            Self::Monomial { polynomial, degree_bound } => {
+                let coeffs = polynomial.iter_nonzero_terms().collect();
+                (false, *degree_bound, 0, coeffs)
+            }
+            Self::Lagrange { evaluations } => {
+                let domain_size = evaluations.domain().size();
+                (true, None, domain_size, evaluations.evaluations.iter().copied().enumerate().collect())
+            }
+            Self::Power { degree, coeff } => {
+                let coeffs = if coeff.is_zero() { vec![] } else { vec![(*degree, *coeff)] };
+                (false, None, 0, coeffs)
+            }
+        }
+    }
+
+    /// Convert `self` into a polynomial that owns its coefficients or evaluations, cloning them if they are borrowed.
+    pub fn into_owned(self) -> PolynomialWithBasis<'static, F> {
+        match self {
+            Self::Monomial { polynomial, degree_bound } => PolynomialWithBasis::Monomial {
+                polynomial: Cow::Owned(polynomial.into_owned().into_owned()),
+                degree_bound,
+            },
+            Self::Lagrange { evaluations } => {
+                PolynomialWithBasis::Lagrange { evaluations: Cow::Owned(evaluations.into_owned()) }
+            }
+            Self::Power { degree, coeff } => PolynomialWithBasis::Power { degree, coeff },
+        }
+    }
+
     pub fn new_monomial_basis_ref(polynomial: &'a Polynomial<F>, degree_bound: Option<usize>) -> Self {
         Self::Monomial { polynomial: Cow::Borrowed(polynomial), degree_bound }
     }
@@ -362,8 +1091,13 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
         Self::Lagrange { evaluations: Cow::Borrowed(evaluations) }
     }
 
+    /// Construct the monomial `coeff * x^degree`.
+    pub fn new_power(degree: usize, coeff: F) -> Self {
+        Self::Power { degree, coeff }
+    }
+
     pub fn is_in_monomial_basis(&self) -> bool {
-        matches!(self, Self::Monomial { .. })
+        matches!(self, Self::Monomial { .. } | Self::Power { .. })
     }
 
     /// Retrieve the degree bound in `self`.
@@ -378,6 +1112,7 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
     pub fn is_sparse(&self) -> bool {
         match self {
             Self::Monomial { polynomial, .. } => matches!(polynomial.as_ref(), Polynomial::Sparse(_)),
+            Self::Power { .. } => true,
             _ => false,
         }
     }
@@ -393,23 +1128,1038 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
         }
     }
 
+    /// If `self` is in Lagrange basis, return the evaluations of the same polynomial over `domain`,
+    /// computed by interpolating `self` and evaluating the result over `domain`.
+    /// Polynomials in monomial basis, and those already over `domain`, are returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain` is smaller than the domain of `self`, since the polynomial may then not be
+    /// determined by its evaluations over `domain`.
+    pub fn resample_to(&self, domain: EvaluationDomain<F>) -> Self {
+        match self {
+            Self::Lagrange { evaluations } if evaluations.domain().size() != domain.size() => {
+                assert!(
+                    evaluations.domain().size() < domain.size(),
+                    "Cannot resample evaluations over a domain of size {} to a smaller domain of size {}",
+                    evaluations.domain().size(),
+                    domain.size()
+                );
+                Self::new_lagrange_basis(evaluations.interpolate_by_ref().evaluate_over_domain(domain))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Evaluate `self` at `point`.
+    /// In monomial basis, evaluating at zero just returns the constant term. In Lagrange basis,
+    /// the constant term is not stored, so evaluating at zero costs as much as at any other point.
+    /// To evaluate many times without allocating, see [`Self::evaluate_with_scratch`].
     pub fn evaluate(&self, point: F) -> F {
         match self {
             Self::Monomial { polynomial, .. } => polynomial.evaluate(point),
+            Self::Power { degree, coeff } => point.pow([*degree as u64]) * coeff,
+            Self::Lagrange { evaluations } => {
+                self.evaluate_with_elements(point, &evaluations.domain().cached_elements())
+            }
+        }
+    }
+
+    /// Evaluate `self` at `point`, reusing the buffers in `scratch` when `self` is in Lagrange basis.
+    ///
+    /// The buffers only grow when the domain is larger than in any previous call, so evaluating
+    /// at many points (or evaluating many polynomials over the same domain) does not allocate.
+    /// The elements of the domain are also kept in `scratch`, and are only recomputed when the domain changes.
+    /// Unlike [`Self::evaluate`], the evaluation runs on the current thread, so prefer [`Self::evaluate`]
+    /// for a single evaluation over a large domain.
+    pub fn evaluate_with_scratch(&self, point: F, scratch: &mut LagrangeEvalScratch<F>) -> F {
+        match self {
+            Self::Monomial { polynomial, .. } => polynomial.evaluate(point),
+            Self::Power { degree, coeff } => point.pow([*degree as u64]) * coeff,
+            Self::Lagrange { evaluations } => {
+                let domain = evaluations.domain();
+                let LagrangeEvalScratch { powers, denominators } = scratch;
+                if powers.len() != domain.size() || (domain.size() > 1 && powers[1] != domain.group_gen) {
+                    powers.clear();
+                    powers.extend(domain.elements());
+                }
+
+                assert_eq!(
+                    evaluations.evaluations.len(),
+                    powers.len(),
+                    "the number of evaluations must match the domain size"
+                );
+
+                // Compute the running products of the denominators `point - g^i`.
+                denominators.clear();
+                let mut product = F::one();
+                for (power, evaluation) in powers.iter().zip(&evaluations.evaluations) {
+                    let denominator = point - power;
+                    if denominator.is_zero() {
+                        // `point` is an element of the domain.
+                        return *evaluation;
+                    }
+                    product *= denominator;
+                    denominators.push(product);
+                }
+
+                // Invert the product of all of the denominators once, and recover the inverse of each
+                // denominator from it and the running products, iterating backwards (Montgomery's trick).
+                let mut inverse = product.inverse().expect("the denominators are nonzero");
+                let mut sum = F::zero();
+                for i in (0..powers.len()).rev() {
+                    let denominator_inverse = match i {
+                        0 => inverse,
+                        _ => inverse * denominators[i - 1],
+                    };
+                    sum += denominator_inverse * powers[i] * evaluations.evaluations[i];
+                    inverse *= point - powers[i];
+                }
+                sum * (point.pow([domain.size() as u64]) - F::one()) * domain.size_inv
+            }
+        }
+    }
+
+    /// Evaluate `self` at `point`, using the precomputed `elements` of the domain
+    /// of `self` when `self` is in Lagrange basis.
+    ///
+    /// This allows callers evaluating many polynomials over the same domain to
+    /// compute the domain elements only once. `elements` is ignored when `self`
+    /// is in monomial basis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of `elements` does not match the domain size. See [`Self::try_evaluate_with_elements`].
+    pub fn evaluate_with_elements(&self, point: F, elements: &[F]) -> F {
+        match self.try_evaluate_with_elements(point, elements) {
+            Ok(evaluation) => evaluation,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Evaluate `self` at `point` as in [`Self::evaluate_with_elements`], returning an error if the number of
+    /// `elements` does not match the domain size.
+    pub fn try_evaluate_with_elements(&self, point: F, elements: &[F]) -> Result<F, PolyCommitError> {
+        match self {
+            Self::Monomial { polynomial, .. } => Ok(polynomial.evaluate(point)),
+            Self::Power { degree, coeff } => Ok(point.pow([*degree as u64]) * coeff),
             Self::Lagrange { evaluations } => {
                 let domain = evaluations.domain();
+                if elements.len() != domain.size() {
+                    return Err(PolyCommitError::DomainMismatch { expected: domain.size(), found: elements.len() });
+                }
                 let degree = domain.size() as u64;
-                let multiplier = (point.pow([degree]) - F::one()) / F::from(degree);
-                let powers: Vec<_> = domain.elements().collect();
-                let mut denominators = cfg_iter!(powers).map(|pow| point - pow).collect::<Vec<_>>();
+                let size_inv = F::from(degree).inverse().ok_or(PolyCommitError::ZeroInInversion)?;
+                let multiplier = (point.pow([degree]) - F::one()) * size_inv;
+                let mut denominators = cfg_iter!(elements).map(|pow| point - pow).collect::<Vec<_>>();
+                if let Some(i) = denominators.iter().position(|d| d.is_zero()) {
+                    // `point` is an element of the domain.
+                    return Ok(evaluations.evaluations[i]);
+                }
                 snarkvm_fields::batch_inversion(&mut denominators);
-                cfg_iter_mut!(denominators)
-                    .zip_eq(powers)
+                Ok(cfg_iter_mut!(denominators)
+                    .zip_eq(elements)
                     .zip_eq(&evaluations.evaluations)
                     .map(|((denom, power), coeff)| *denom * power * coeff)
                     .sum::<F>()
-                    * multiplier
+                    * multiplier)
+            }
+        }
+    }
+
+    /// Evaluate `self` at each of `points`.
+    ///
+    /// When `self` is in Lagrange basis, the `points.len() * domain.size()` denominators `point - g^i`
+    /// are inverted by a single batch inversion, instead of one batch inversion per point as when
+    /// calling [`Self::evaluate`] for each point, and the domain elements are computed only once.
+    pub fn evaluate_at_points(&self, points: &[F]) -> Vec<F> {
+        match self {
+            Self::Monomial { polynomial, .. } => cfg_iter!(points).map(|point| polynomial.evaluate(*point)).collect(),
+            Self::Power { degree, coeff } => {
+                cfg_iter!(points).map(|point| point.pow([*degree as u64]) * coeff).collect()
+            }
+            Self::Lagrange { evaluations } => {
+                let domain = evaluations.domain();
+                let elements = domain.cached_elements();
+                let elements = elements.as_slice();
+                let degree = domain.size() as u64;
+                let size_inv = F::from(degree).inverse().expect("the size of a domain is nonzero in its field");
+                let mut denominators =
+                    points.iter().flat_map(|point| elements.iter().map(move |pow| *point - pow)).collect::<Vec<_>>();
+                snarkvm_fields::batch_inversion(&mut denominators);
+                cfg_chunks!(denominators, elements.len())
+                    .zip_eq(points)
+                    .map(|(inverses, point)| {
+                        let vanishing = point.pow([degree]) - F::one();
+                        if vanishing.is_zero() {
+                            // `point` is an element of the domain, so its denominator was skipped by the inversion.
+                            let i = elements.iter().position(|e| e == point).expect("`point` is in the domain");
+                            return evaluations.evaluations[i];
+                        }
+                        let multiplier = vanishing * size_inv;
+                        inverses
+                            .iter()
+                            .zip(elements)
+                            .zip(&evaluations.evaluations)
+                            .map(|((denom, power), coeff)| *denom * power * coeff)
+                            .sum::<F>()
+                            * multiplier
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn test_labeled_polynomial_with_basis_serialization() {
+        let rng = &mut TestRng::default();
+
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain((0..16).map(|_| Fr::rand(rng)).collect(), domain);
+        let dense = DensePolynomial::<Fr>::rand(10, rng);
+        let sparse = SparsePolynomial::from_coefficients([(3, Fr::rand(rng)), (7, Fr::rand(rng))]);
+
+        let terms = vec![
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&evaluations)),
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, Some(12))),
+            (Fr::rand(rng), PolynomialWithBasis::new_sparse_monomial_basis(sparse, None)),
+        ];
+        let expected = LabeledPolynomialWithBasis::new_linear_combination("test".to_string(), terms, Some(1));
+
+        let mut bytes = Vec::new();
+        expected.serialize_compressed(&mut bytes).unwrap();
+        let candidate = LabeledPolynomialWithBasis::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+
+        let point = Fr::rand(rng);
+        assert_eq!(expected.info(), candidate.info());
+        assert_eq!(expected.degree(), candidate.degree());
+        assert_eq!(expected.degree_bound(), candidate.degree_bound());
+        assert_eq!(expected.evaluate(point), candidate.evaluate(point));
+    }
+
+    #[test]
+    fn test_sum_with_hiding() {
+        let rng = &mut TestRng::default();
+
+        let small = DensePolynomial::<Fr>::rand(3, rng);
+        let large = DensePolynomial::<Fr>::rand(9, rng);
+        let hiding_bound = 15;
+        let lc = |terms: Vec<_>| LabeledPolynomialWithBasis::new_linear_combination("test", terms, Some(hiding_bound));
+        let single = lc(vec![(Fr::one(), PolynomialWithBasis::new_dense_monomial_basis_ref(&small, None))]);
+        let combined = lc(vec![
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&small, None)),
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&large, None)),
+        ]);
+
+        let point = Fr::rand(rng);
+        for lc in [single, combined] {
+            let mut sum = lc.sum().collect::<Vec<_>>();
+            assert_eq!(sum.iter().map(|p| p.evaluate(point)).sum::<Fr>(), lc.evaluate(point));
+
+            // The dense polynomial is long enough to be blinded in place.
+            let mask = DensePolynomial::<Fr>::rand(hiding_bound, rng);
+            let dense = sum
+                .iter_mut()
+                .find_map(|p| match p {
+                    PolynomialWithBasis::Monomial { polynomial, .. } => polynomial.to_mut().as_dense_mut(),
+                    _ => None,
+                })
+                .unwrap();
+            assert_eq!(dense.coeffs.len(), hiding_bound + 1);
+            dense.coeffs[..=hiding_bound].iter_mut().zip(&mask.coeffs).for_each(|(c, m)| *c += m);
+            assert_eq!(
+                sum.iter().map(|p| p.evaluate(point)).sum::<Fr>(),
+                lc.evaluate(point) + mask.evaluate(point)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum_with_resampling() {
+        let rng = &mut TestRng::default();
+
+        let dense = DensePolynomial::<Fr>::rand(7, rng);
+        let evaluations = |size: usize, rng: &mut TestRng| {
+            let domain = EvaluationDomain::new(size).unwrap();
+            EvaluationsOnDomain::from_vec_and_domain((0..size).map(|_| Fr::rand(rng)).collect(), domain)
+        };
+        let (small, medium, large) = (evaluations(4, rng), evaluations(8, rng), evaluations(16, rng));
+        let terms = vec![
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&small)),
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, None)),
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&large)),
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&medium)),
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&small)),
+        ];
+        let lc = LabeledPolynomialWithBasis::new_linear_combination("test".to_string(), terms, None);
+        let point = Fr::rand(rng);
+
+        // Without resampling, there is one Lagrange polynomial per domain.
+        let sum = lc.sum().collect::<Vec<_>>();
+        assert_eq!(sum.iter().filter(|p| p.is_in_lagrange_basis()).count(), 3);
+        assert_eq!(sum.iter().map(|p| p.evaluate(point)).sum::<Fr>(), lc.evaluate(point));
+
+        let resampled = lc.sum_with_resampling().collect::<Vec<_>>();
+        let lagrange = resampled.iter().filter(|p| p.is_in_lagrange_basis()).collect::<Vec<_>>();
+        assert_eq!(lagrange.len(), 1);
+        assert_eq!(lagrange[0].domain().unwrap().size(), 16);
+        assert_eq!(resampled.iter().map(|p| p.evaluate(point)).sum::<Fr>(), lc.evaluate(point));
+
+        // Resampling preserves the polynomial, and leaves monomial terms unchanged.
+        let domain = EvaluationDomain::new(32).unwrap();
+        let small = PolynomialWithBasis::new_lagrange_basis_ref(&small);
+        assert_eq!(small.resample_to(domain).evaluate(point), small.evaluate(point));
+        let dense = PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, None);
+        assert!(dense.resample_to(domain).is_in_monomial_basis());
+    }
+
+    #[test]
+    #[should_panic(expected = "to a smaller domain")]
+    fn test_resample_to_smaller_domain() {
+        let evaluations =
+            EvaluationsOnDomain::from_vec_and_domain(vec![Fr::one(); 8], EvaluationDomain::new(8).unwrap());
+        let _ = PolynomialWithBasis::new_lagrange_basis(evaluations).resample_to(EvaluationDomain::new(4).unwrap());
+    }
+
+    #[test]
+    fn test_degree_bounds() {
+        let rng = &mut TestRng::default();
+
+        let dense = DensePolynomial::<Fr>::rand(7, rng);
+        let evaluations =
+            EvaluationsOnDomain::from_vec_and_domain(vec![Fr::one(); 8], EvaluationDomain::new(8).unwrap());
+        let mut terms = [Some(20), None, Some(10), Some(20), Some(15)]
+            .into_iter()
+            .map(|degree_bound| {
+                (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, degree_bound))
+            })
+            .collect::<Vec<_>>();
+        terms.push((Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&evaluations)));
+        let lc = LabeledPolynomialWithBasis::new_linear_combination("test".to_string(), terms, None);
+        assert_eq!(lc.degree_bounds().collect::<Vec<_>>(), vec![10, 15, 20]);
+        assert_eq!(lc.degree_bound(), Some(20));
+    }
+
+    #[test]
+    fn test_group_summary() {
+        let rng = &mut TestRng::default();
+
+        let dense = DensePolynomial::<Fr>::rand(7, rng);
+        let sparse = SparsePolynomial::from_coefficients([(2, Fr::rand(rng)), (5, Fr::rand(rng))]);
+        let small = EvaluationsOnDomain::from_vec_and_domain(vec![Fr::rand(rng); 8], EvaluationDomain::new(8).unwrap());
+        let large =
+            EvaluationsOnDomain::from_vec_and_domain(vec![Fr::rand(rng); 16], EvaluationDomain::new(16).unwrap());
+        let terms = vec![
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, None)),
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, Some(14))),
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, None)),
+            (Fr::rand(rng), PolynomialWithBasis::new_sparse_monomial_basis_ref(&sparse, None)),
+            (Fr::rand(rng), PolynomialWithBasis::new_sparse_monomial_basis_ref(&sparse, Some(14))),
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&small)),
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&large)),
+            (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&small)),
+        ];
+        let lc = LabeledPolynomialWithBasis::new_linear_combination("test".to_string(), terms, None);
+
+        let summary = lc.group_summary();
+        assert_eq!(summary.lagrange_terms, [(8, 2), (16, 1)].into_iter().collect());
+        assert_eq!(summary.dense_terms, [(None, 2), (Some(14), 1)].into_iter().collect());
+        assert_eq!(summary.sparse_terms, 2);
+        assert_eq!(lc.degree_bounds().collect::<Vec<_>>(), vec![14]);
+        // `sum` returns one polynomial per Lagrange domain and dense degree bound, plus one sparse polynomial.
+        assert_eq!(lc.sum().count(), summary.lagrange_terms.len() + summary.dense_terms.len() + 1);
+    }
+
+    #[test]
+    fn test_power() {
+        let rng = &mut TestRng::default();
+
+        let (degree, coeff) = (5, Fr::rand(rng));
+        let power = PolynomialWithBasis::new_power(degree, coeff);
+        let point = Fr::rand(rng);
+        assert_eq!(power.evaluate(point), coeff * point.pow([5]));
+        assert_eq!(power.evaluate_at_points(&[point]), vec![power.evaluate(point)]);
+
+        // A power serializes as the equivalent sparse polynomial.
+        let sparse = SparsePolynomial::from_coefficients([(degree, coeff)]);
+        let expected = PolynomialWithBasis::new_sparse_monomial_basis_ref(&sparse, None);
+        let mut bytes = Vec::new();
+        power.serialize_compressed(&mut bytes).unwrap();
+        let mut expected_bytes = Vec::new();
+        expected.serialize_compressed(&mut expected_bytes).unwrap();
+        assert_eq!(bytes, expected_bytes);
+
+        // `sum` folds powers into the sparse polynomial.
+        let dense = DensePolynomial::<Fr>::rand(7, rng);
+        let terms = vec![
+            (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, None)),
+            (Fr::rand(rng), PolynomialWithBasis::new_sparse_monomial_basis_ref(&sparse, None)),
+            (Fr::rand(rng), power),
+            (Fr::rand(rng), PolynomialWithBasis::new_power(9, Fr::one())),
+        ];
+        let lc = LabeledPolynomialWithBasis::new_linear_combination("test".to_string(), terms, None);
+        assert_eq!(lc.degree(), 9);
+        assert_eq!(lc.group_summary().sparse_terms, 3);
+        let sum = lc.sum().collect::<Vec<_>>();
+        assert_eq!(sum.len(), 2);
+        assert!(sum.iter().all(|p| !matches!(p, PolynomialWithBasis::Power { .. })));
+        assert_eq!(sum.iter().map(|p| p.evaluate(point)).sum::<Fr>(), lc.evaluate(point));
+    }
+
+    #[test]
+    fn test_evaluate_at_points() {
+        let rng = &mut TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let lagrange_evals = (0..16).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain(lagrange_evals.clone(), domain);
+        let lagrange = PolynomialWithBasis::new_lagrange_basis(evaluations);
+        let monomial = PolynomialWithBasis::new_dense_monomial_basis(DensePolynomial::rand(20, rng), None);
+
+        for num_points in [0, 1, 5] {
+            // Random points, along with elements of the domain, at which the stored evaluations are returned.
+            let mut points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            points.extend(domain.elements().step_by(5).take(num_points));
+            for p in [&lagrange, &monomial] {
+                let expected = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+                assert_eq!(p.evaluate_at_points(&points), expected);
+            }
+            for (i, element) in domain.elements().enumerate().step_by(5).take(num_points) {
+                assert_eq!(lagrange.evaluate_at_points(&[element]), vec![lagrange_evals[i]]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_barycentric_batch_evaluate() {
+        let rng = &mut TestRng::default();
+        let evals = [8, 16, 1, 16, 32, 8]
+            .into_iter()
+            .map(|size| {
+                let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+                EvaluationsOnDomain::from_vec_and_domain((0..size).map(|_| Fr::rand(rng)).collect(), domain)
+            })
+            .collect::<Vec<_>>();
+        let evals = evals.iter().collect::<Vec<_>>();
+
+        // Out-of-domain points, and elements of some or all of the domains.
+        let g = EvaluationDomain::<Fr>::new(16).unwrap().group_gen;
+        for z in [Fr::rand(rng), Fr::rand(rng), g, g.square(), Fr::one()] {
+            let expected = evals
+                .iter()
+                .map(|e| PolynomialWithBasis::new_lagrange_basis((*e).clone()).evaluate(z))
+                .collect::<Vec<_>>();
+            assert_eq!(barycentric_batch_evaluate(&evals, z), expected);
+        }
+        assert!(barycentric_batch_evaluate::<Fr>(&[], Fr::rand(rng)).is_empty());
+    }
+
+    #[test]
+    fn test_evaluate_with_scratch() {
+        let rng = &mut TestRng::default();
+        let mut scratch = LagrangeEvalScratch::new();
+        for size in [1, 16, 8, 16] {
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            let evaluations =
+                EvaluationsOnDomain::from_vec_and_domain((0..size).map(|_| Fr::rand(rng)).collect(), domain);
+            let lagrange = PolynomialWithBasis::new_lagrange_basis_ref(&evaluations);
+            let dense = evaluations.interpolate_by_ref();
+
+            for _ in 0..3 {
+                let point = Fr::rand(rng);
+                assert_eq!(lagrange.evaluate_with_scratch(point, &mut scratch), dense.evaluate(point));
+                assert_eq!(lagrange.evaluate(point), dense.evaluate(point));
+            }
+            // Points in the domain are handled too.
+            for (element, evaluation) in domain.elements().zip(&evaluations.evaluations) {
+                assert_eq!(lagrange.evaluate_with_scratch(element, &mut scratch), *evaluation);
+            }
+        }
+
+        // Once the buffers are large enough, they are not reallocated.
+        let capacities = (scratch.powers.capacity(), scratch.denominators.capacity());
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain(vec![Fr::rand(rng); 8], domain);
+        PolynomialWithBasis::new_lagrange_basis(evaluations).evaluate_with_scratch(Fr::rand(rng), &mut scratch);
+        assert_eq!((scratch.powers.capacity(), scratch.denominators.capacity()), capacities);
+    }
+
+    #[test]
+    fn test_try_evaluate_with_elements() {
+        let rng = &mut TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain((0..16).map(|_| Fr::rand(rng)).collect(), domain);
+        let lagrange = PolynomialWithBasis::new_lagrange_basis(evaluations.clone());
+        let elements = domain.elements().collect::<Vec<_>>();
+
+        let point = Fr::rand(rng);
+        assert_eq!(lagrange.try_evaluate_with_elements(point, &elements), Ok(lagrange.evaluate(point)));
+        assert_eq!(
+            lagrange.try_evaluate_with_elements(point, &elements[..8]),
+            Err(PolyCommitError::DomainMismatch { expected: 16, found: 8 })
+        );
+        // At an element of the domain, the stored evaluation is returned.
+        for (element, evaluation) in elements.iter().zip(&evaluations.evaluations) {
+            assert_eq!(lagrange.try_evaluate_with_elements(*element, &elements), Ok(*evaluation));
+            assert_eq!(lagrange.evaluate_with_elements(*element, &elements), *evaluation);
+        }
+    }
+
+    #[test]
+    fn test_labeled_polynomial_as_evaluations() {
+        let rng = &mut TestRng::default();
+
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let dense = LabeledPolynomial::new("dense".to_string(), DensePolynomial::<Fr>::rand(15, rng), None, None);
+        let sparse = SparsePolynomial::from_coefficients([(3, Fr::rand(rng)), (15, Fr::rand(rng))]);
+        let sparse = LabeledPolynomial::new("sparse".to_string(), sparse, None, None);
+        for p in [&dense, &sparse] {
+            let evaluations = p.as_evaluations(domain).unwrap();
+            for (element, evaluation) in domain.elements().zip(&evaluations.evaluations) {
+                assert_eq!(p.evaluate(element), *evaluation);
+            }
+            assert_eq!(evaluations.interpolate_by_ref(), p.to_dense().into_owned());
+        }
+
+        let too_large =
+            LabeledPolynomial::new("too_large".to_string(), DensePolynomial::<Fr>::rand(16, rng), None, None);
+        assert!(matches!(
+            too_large.as_evaluations(domain),
+            Err(PCError::PolynomialDegreeTooLargeForDomain { poly_degree: 16, domain_size: 16, .. })
+        ));
+    }
+
+    #[test]
+    fn test_evaluations_into_labeled_polynomial() {
+        let rng = &mut TestRng::default();
+
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let p = LabeledPolynomial::new("p".to_string(), DensePolynomial::<Fr>::rand(11, rng), Some(12), Some(1));
+        let evaluations = p.as_evaluations(domain).unwrap();
+        let interpolated = evaluations.clone().into_labeled_polynomial("p".to_string(), Some(12), Some(1)).unwrap();
+        assert_eq!(interpolated.info(), p.info());
+        assert_eq!(interpolated.polynomial(), p.polynomial());
+
+        assert!(matches!(
+            evaluations.into_labeled_polynomial("p".to_string(), Some(10), None),
+            Err(PCError::PolyCommitError(PolyCommitError::DegreeBoundExceeded { degree: 11, degree_bound: 10, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_concat_along_degree() {
+        let rng = &mut TestRng::default();
+
+        let stride = 8;
+        let polynomials = [7, 0, 3]
+            .into_iter()
+            .enumerate()
+            .map(|(i, degree)| {
+                LabeledPolynomial::new(format!("p{i}"), DensePolynomial::<Fr>::rand(degree, rng), None, None)
+            })
+            .chain([LabeledPolynomial::new("zero".to_string(), DensePolynomial::zero(), None, None)])
+            .collect::<Vec<_>>();
+        let packed = LabeledPolynomial::concat_along_degree(&polynomials, stride).unwrap();
+
+        let point = Fr::rand(rng);
+        let expected = polynomials
+            .iter()
+            .enumerate()
+            .map(|(i, p)| point.pow([(i * stride) as u64]) * p.evaluate(point))
+            .sum::<Fr>();
+        assert_eq!(packed.evaluate(point), expected);
+
+        let unpacked = LabeledPolynomial::unpack(&packed, stride, polynomials.len());
+        assert_eq!(unpacked.len(), polynomials.len());
+        for (p, q) in polynomials.iter().zip(&unpacked) {
+            assert_eq!(p.to_dense().as_ref(), q);
+        }
+
+        assert!(matches!(
+            LabeledPolynomial::concat_along_degree(&polynomials, 7),
+            Err(PolyCommitError::StrideExceeded { degree: 7, stride: 7, .. })
+        ));
+
+        // A packed length that overflows is rejected before allocating.
+        assert!(matches!(
+            LabeledPolynomial::concat_along_degree(&polynomials, usize::MAX),
+            Err(PolyCommitError::PackedLengthOverflow { count: 4, stride: usize::MAX })
+        ));
+        let unpacked = LabeledPolynomial::unpack(&packed, usize::MAX, 2);
+        assert_eq!(unpacked, vec![packed.clone(), DensePolynomial::zero()]);
+    }
+
+    #[test]
+    fn test_shifted_for_degree_bound() {
+        let rng = &mut TestRng::default();
+
+        let polynomial = DensePolynomial::<Fr>::rand(10, rng);
+        let point = Fr::rand(rng);
+
+        let unbounded = LabeledPolynomial::new("unbounded".to_string(), polynomial.clone(), None, None);
+        assert_eq!(unbounded.shifted_for_degree_bound(20).unwrap(), polynomial);
+
+        let bounded = LabeledPolynomial::new("bounded".to_string(), polynomial.clone(), Some(12), None);
+        let shifted = bounded.shifted_for_degree_bound(20).unwrap();
+        assert_eq!(shifted.degree(), 18);
+        assert_eq!(shifted.evaluate(point), point.pow([8]) * polynomial.evaluate(point));
+
+        assert!(matches!(
+            bounded.shifted_for_degree_bound(11),
+            Err(PCError::IncorrectDegreeBound { degree_bound: 12, max_degree: 11, .. })
+        ));
+    }
+
+    #[test]
+    fn test_linear_combination_by_label() {
+        let rng = &mut TestRng::default();
+
+        let polys = (0..3)
+            .map(|i| {
+                let label = PolynomialLabel::from(format!("p{i}"));
+                let p = LabeledPolynomial::new(label.clone(), DensePolynomial::<Fr>::rand(5 + i, rng), None, Some(i));
+                (label, p)
+            })
+            .collect::<HashMap<_, _>>();
+        let terms = vec![("p0".into(), Fr::rand(rng)), ("p2".into(), Fr::rand(rng))];
+
+        let lc = linear_combination("lc", &polys, &terms).unwrap();
+        let point = Fr::rand(rng);
+        let expected: Fr = terms.iter().map(|(label, coeff)| *coeff * polys[label].evaluate(point)).sum();
+        assert_eq!(lc.label(), "lc");
+        assert_eq!(lc.hiding_bound(), Some(2));
+        assert_eq!(lc.evaluate(point), expected);
+
+        let missing = vec![("p3".into(), Fr::rand(rng))];
+        assert!(matches!(
+            linear_combination("lc", &polys, &missing),
+            Err(PCError::MissingPolynomial { label }) if label == "p3"
+        ));
+    }
+
+    #[test]
+    fn test_try_new_linear_combination() {
+        let rng = &mut TestRng::default();
+
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+        let coeff = Fr::rand(rng);
+        let terms = || vec![(coeff, PolynomialWithBasis::new_dense_monomial_basis_ref(&p, None))];
+        let label = || "test".to_string();
+
+        assert!(LabeledPolynomialWithBasis::try_new_linear_combination(label(), terms(), None, 16).is_ok());
+        assert!(LabeledPolynomialWithBasis::try_new_linear_combination(label(), terms(), Some(16), 16).is_ok());
+        assert!(matches!(
+            LabeledPolynomialWithBasis::<Fr>::try_new_linear_combination(label(), vec![], Some(1), 16),
+            Err(PCError::EmptyLinearCombination(l)) if l == "test"
+        ));
+        assert!(matches!(
+            LabeledPolynomialWithBasis::try_new_linear_combination(label(), terms(), Some(0), 16),
+            Err(PCError::HidingBoundIsZero)
+        ));
+        assert!(matches!(
+            LabeledPolynomialWithBasis::try_new_linear_combination(label(), terms(), Some(17), 16),
+            Err(PCError::HidingBoundToolarge { hiding_poly_degree: 17, num_powers: 17 })
+        ));
+    }
+
+    #[test]
+    fn test_polynomial_map() {
+        let rng = &mut TestRng::default();
+        let a = LabeledPolynomial::new("a".to_string(), DensePolynomial::<Fr>::rand(4, rng), None, None);
+        let b = LabeledPolynomial::new("b".to_string(), DensePolynomial::<Fr>::rand(4, rng), None, None);
+        let mut map = PolynomialMap::try_from(vec![a.clone(), b.clone()]).unwrap();
+
+        assert_eq!(map.get_or_err("a").unwrap(), &a);
+        assert!(matches!(map.get_or_err("c"), Err(PCError::MissingPolynomial { label }) if label == "c"));
+
+        let ordered = map.iter_in_order(&["b", "a", "b"]).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(ordered, vec![&b, &a, &b]);
+        assert!(map.iter_in_order(&["a", "c"]).collect::<Result<Vec<_>, _>>().is_err());
+
+        // Inserting a duplicate label fails and keeps the original polynomial.
+        let duplicate = LabeledPolynomial::new("a".to_string(), DensePolynomial::<Fr>::rand(4, rng), None, None);
+        assert!(matches!(map.insert_labeled(duplicate), Err(PCError::DuplicatePolynomial { label }) if label == "a"));
+        assert_eq!(map.get_or_err("a").unwrap(), &a);
+        assert_eq!(map.len(), 2);
+
+        // The map can be used wherever a map of labeled polynomials is expected.
+        let combination = linear_combination("lc", &map, &[("a".into(), Fr::one())]).unwrap();
+        assert_eq!(combination.polynomial.len(), 1);
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let rng = &mut TestRng::default();
+        let a = DensePolynomial::<Fr>::rand(8, rng);
+        let b = DensePolynomial::<Fr>::rand(8, rng);
+        let sparse = SparsePolynomial::from_coefficients([(0, Fr::one()), (5, Fr::one())]);
+        let dense_sparse = DensePolynomial::from(sparse.clone());
+        // `a` with trailing zeros, which are not part of its value.
+        let padded = DensePolynomial { coeffs: [a.coeffs(), &[Fr::zero(); 3]].concat() };
+        let domain = EvaluationDomain::new(8).unwrap();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain((0..8).map(|_| Fr::rand(rng)).collect(), domain);
+        let (x, y, z) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+
+        let mut lhs = LabeledPolynomialWithBasis::new_linear_combination(
+            "test".to_string(),
+            vec![
+                (x, PolynomialWithBasis::new_dense_monomial_basis_ref(&padded, None)),
+                (y, PolynomialWithBasis::new_lagrange_basis_ref(&evaluations)),
+                (Fr::zero(), PolynomialWithBasis::new_dense_monomial_basis_ref(&b, None)),
+                (z, PolynomialWithBasis::new_sparse_monomial_basis_ref(&sparse, Some(10))),
+                (z, PolynomialWithBasis::new_dense_monomial_basis_ref(&a, None)),
+                (-z, PolynomialWithBasis::new_dense_monomial_basis_ref(&b, Some(10))),
+            ],
+            None,
+        );
+        let mut rhs = LabeledPolynomialWithBasis::new_linear_combination(
+            "test".to_string(),
+            vec![
+                (z, PolynomialWithBasis::new_dense_monomial_basis_ref(&dense_sparse, Some(10))),
+                (x + z, PolynomialWithBasis::new_dense_monomial_basis_ref(&a, None)),
+                (y, PolynomialWithBasis::new_lagrange_basis_ref(&evaluations)),
+                (z, PolynomialWithBasis::new_dense_monomial_basis_ref(&b, Some(10))),
+                (-z - z, PolynomialWithBasis::new_dense_monomial_basis_ref(&b, Some(10))),
+            ],
+            None,
+        );
+        // `lhs` cannot be evaluated before it is canonicalized, since `padded` has trailing zeros.
+        let point = Fr::rand(rng);
+        let evaluation = rhs.evaluate(point);
+        lhs.canonicalize();
+        rhs.canonicalize();
+
+        assert_eq!(lhs.polynomial.len(), 4);
+        assert_eq!(lhs.evaluate(point), evaluation);
+        assert_eq!(rhs.evaluate(point), evaluation);
+        let serialize = |p: &LabeledPolynomialWithBasis<Fr>| {
+            let mut bytes = Vec::new();
+            p.serialize_compressed(&mut bytes).unwrap();
+            bytes
+        };
+        // Equal values serialize identically, whichever representation of a merged term comes first.
+        assert_eq!(serialize(&lhs), serialize(&rhs));
+        assert!(lhs.polynomial.last().unwrap().1.is_in_lagrange_basis());
+    }
+
+    #[test]
+    fn test_content_hash() {
+        use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+        let hash = |p: &LabeledPolynomial<Fr>| {
+            let mut hasher = DefaultHasher::new();
+            p.content_hash(&mut hasher);
+            hasher.finish()
+        };
+        let rng = &mut TestRng::default();
+        let sparse = SparsePolynomial::rand(5, 20, rng);
+        let mut dense = DensePolynomial::from(sparse.clone());
+        dense.coeffs.extend([Fr::zero(); 3]);
+
+        let a = LabeledPolynomial::new("a".to_string(), sparse, Some(20), Some(1));
+        let b = LabeledPolynomial::new("b".to_string(), dense, Some(20), Some(1));
+        assert_eq!(hash(&a), hash(&b));
+
+        // The bounds are part of the content.
+        let c = LabeledPolynomial::new("a".to_string(), a.polynomial.clone(), None, Some(1));
+        let d = LabeledPolynomial::new("a".to_string(), a.polynomial.clone(), Some(20), None);
+        assert_ne!(hash(&a), hash(&c));
+        assert_ne!(hash(&a), hash(&d));
+
+        // So are the coefficients.
+        let mut e = a.clone();
+        e.set_coefficient(0, e.coefficient(0) + Fr::one());
+        assert_ne!(hash(&a), hash(&e));
+    }
+
+    #[test]
+    fn test_secure_clear() {
+        let rng = &mut TestRng::default();
+        let dense = DensePolynomial::<Fr>::rand(10, rng);
+        let sparse = SparsePolynomial::<Fr>::rand(3, 10, rng);
+
+        let mut padded = dense.clone();
+        padded.coeffs.reserve(5);
+        let capacity = padded.coeffs.capacity();
+        let mut hiding = LabeledPolynomial::new("dense".to_string(), padded, None, Some(1));
+        hiding.secure_clear();
+        assert!(hiding.is_zero());
+        assert_eq!(hiding.polynomial().as_dense().unwrap().coeffs.capacity(), capacity);
+
+        let mut hiding = LabeledPolynomial::new("sparse".to_string(), sparse, None, Some(1));
+        hiding.secure_clear();
+        assert!(hiding.is_zero());
+
+        // Non-hiding polynomials are left untouched.
+        let mut public = LabeledPolynomial::new("public".to_string(), dense.clone(), None, None);
+        public.secure_clear();
+        assert_eq!(public.polynomial().as_dense().unwrap(), &dense);
+    }
+
+
+    #[test]
+    fn test_into_owned() {
+        let rng = &mut TestRng::default();
+        let dense = DensePolynomial::<Fr>::rand(8, rng);
+        let sparse = SparsePolynomial::<Fr>::rand(3, 20, rng);
+        let domain = EvaluationDomain::new(8).unwrap();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain((0..8).map(|_| Fr::rand(rng)).collect(), domain);
+        let point = Fr::rand(rng);
+
+        let owned = {
+            let polynomial = Polynomial::from(&sparse);
+            let lc = LabeledPolynomialWithBasis::new_linear_combination(
+                "test".to_string(),
+                vec![
+                    (Fr::rand(rng), PolynomialWithBasis::new_dense_monomial_basis_ref(&dense, Some(10))),
+                    (Fr::rand(rng), PolynomialWithBasis::new_monomial_basis_ref(&polynomial, None)),
+                    (Fr::rand(rng), PolynomialWithBasis::new_lagrange_basis_ref(&evaluations)),
+                ],
+                Some(1),
+            );
+            let expected = lc.evaluate(point);
+            let owned = lc.into_owned();
+            assert_eq!(owned.evaluate(point), expected);
+            owned
+        };
+        // `owned` outlives the borrowed `polynomial`, and holds no borrowed terms.
+        assert_eq!(owned.info(), &PolynomialInfo::new("test".to_string(), None, Some(1)));
+        assert_eq!(owned.degree_bound(), Some(10));
+        for (_, p) in &owned.polynomial {
+            match p {
+                PolynomialWithBasis::Monomial { polynomial, .. } => {
+                    assert!(matches!(
+                        polynomial,
+                        Cow::Owned(Polynomial::Sparse(Cow::Owned(_)) | Polynomial::Dense(Cow::Owned(_)))
+                    ))
+                }
+                PolynomialWithBasis::Lagrange { evaluations } => assert!(matches!(evaluations, Cow::Owned(_))),
+                PolynomialWithBasis::Power { .. } => {}
             }
         }
     }
+
+    #[test]
+    fn test_effective_degree() {
+        let rng = &mut TestRng::default();
+        let p = LabeledPolynomial::new("p".to_string(), DensePolynomial::<Fr>::rand(7, rng), Some(10), None);
+        assert_eq!(p.effective_degree(), 7);
+        assert_eq!(p.enforced_degree(), Some(10));
+
+        let q = LabeledPolynomial::new("q".to_string(), DensePolynomial::<Fr>::rand(7, rng), None, None);
+        assert_eq!(q.effective_degree(), 7);
+        assert_eq!(q.enforced_degree(), None);
+
+        assert_eq!(p.check_degree_bound(), Ok(()));
+        assert_eq!(q.check_degree_bound(), Ok(()));
+        let r = LabeledPolynomial::new("r".to_string(), DensePolynomial::<Fr>::rand(12, rng), Some(10), None);
+        assert_eq!(
+            r.check_degree_bound(),
+            Err(PolyCommitError::DegreeBoundExceeded { label: "r".to_string(), degree: 12, degree_bound: 10 })
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exceeds its degree bound")]
+    fn test_effective_degree_exceeds_bound() {
+        let rng = &mut TestRng::default();
+        let p = LabeledPolynomial::new("p".to_string(), DensePolynomial::<Fr>::rand(12, rng), Some(10), None);
+        let _ = p.effective_degree();
+    }
+
+    #[test]
+    fn test_coefficient_access() {
+        let rng = &mut TestRng::default();
+        let dense = DensePolynomial::<Fr>::rand(5, rng);
+        let sparse = SparsePolynomial::from_coefficients([(2, Fr::one()), (9, Fr::rand(rng))]);
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+
+        for p in [Polynomial::from(dense), Polynomial::from(sparse)] {
+            let mut p = LabeledPolynomial::new("test".to_string(), p, None, None);
+            let coeffs = p.coeffs().map(|(i, c)| (i, *c)).collect::<Vec<_>>();
+            for (i, c) in &coeffs {
+                assert_eq!(p.coefficient(*i), *c);
+            }
+            assert_eq!(p.coefficient(100), Fr::zero());
+
+            let point = Fr::rand(rng);
+            let expected = p.evaluate(point) + (a - p.coefficient(3)) * point.pow([3]) + b * point.pow([20]);
+            p.set_coefficient(3, a);
+            p.set_coefficient(20, b);
+            assert_eq!(p.coefficient(3), a);
+            assert_eq!(p.coefficient(20), b);
+            assert_eq!(p.degree(), 20);
+            assert_eq!(p.evaluate(point), expected);
+
+            // Zeroing the leading coefficient trims the polynomial.
+            p.set_coefficient(20, Fr::zero());
+            p.set_coefficient(50, Fr::zero());
+            assert!(p.is_canonical());
+            assert!(p.degree() < 20);
+        }
+    }
+
+    #[test]
+    fn test_labeled_polynomial_serialization_modes() {
+        let rng = &mut TestRng::default();
+        let dense = DensePolynomial::<Fr>::rand(20, rng);
+        let sparse = SparsePolynomial::<Fr>::rand(4, 20, rng);
+
+        for p in [Polynomial::from(dense), Polynomial::from(sparse)] {
+            let expected = LabeledPolynomial::new("test".to_string(), p, Some(20), Some(1));
+            for compress in [Compress::Yes, Compress::No] {
+                let mut bytes = Vec::new();
+                expected.serialize_with_mode(&mut bytes, compress).unwrap();
+
+                let candidate =
+                    LabeledPolynomial::<Fr>::deserialize_with_mode(&bytes[..], compress, Validate::Yes).unwrap();
+                assert_eq!(candidate.info(), expected.info());
+                // Sparse polynomials are deserialized in dense form.
+                assert_eq!(candidate.to_dense(), expected.to_dense());
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_labeled_polynomial_serde() {
+        let rng = &mut TestRng::default();
+        let dense = DensePolynomial::<Fr>::rand(20, rng);
+        let sparse = SparsePolynomial::<Fr>::rand(4, 20, rng);
+
+        for (p, tag) in [(Polynomial::from(dense), "Dense"), (Polynomial::from(sparse), "Sparse")] {
+            let expected = LabeledPolynomial::new("test".to_string(), p, Some(20), Some(1));
+            let json = serde_json::to_string(&expected).unwrap();
+            assert!(json.contains(&format!("\"{tag}\"")));
+            // Unlike the canonical format, the representation is preserved.
+            assert_eq!(serde_json::from_str::<LabeledPolynomial<Fr>>(&json).unwrap(), expected);
+            let bytes = bincode::serialize(&expected).unwrap();
+            assert_eq!(bincode::deserialize::<LabeledPolynomial<Fr>>(&bytes).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_labeled_polynomial_deserialization_validation() {
+        let rng = &mut TestRng::default();
+        let deserialize = |bytes: &[u8], validate| {
+            LabeledPolynomial::<Fr>::deserialize_with_mode(bytes, Compress::No, validate).map(|p| p.info().clone())
+        };
+
+        let p = LabeledPolynomial::new("test".to_string(), DensePolynomial::<Fr>::rand(4, rng), None, None);
+        let mut bytes = Vec::new();
+        p.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(deserialize(&bytes, Validate::Yes).is_ok());
+
+        // A coefficient that is not less than the modulus is rejected.
+        let mut out_of_range = bytes.clone();
+        let len = out_of_range.len();
+        out_of_range[len - 32..].fill(0xff);
+        assert!(deserialize(&out_of_range, Validate::Yes).is_err());
+
+        // A dense polynomial with trailing zeros is rejected when validating.
+        let mut coeffs = p.to_dense().coeffs.clone();
+        coeffs.push(Fr::zero());
+        let trailing_zero = LabeledPolynomial::new("test".to_string(), DensePolynomial { coeffs }, None, None);
+        let mut bytes = Vec::new();
+        trailing_zero.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(deserialize(&bytes, Validate::Yes).is_err());
+        assert!(deserialize(&bytes, Validate::No).is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_labeled_polynomial_serde_validation() {
+        let rng = &mut TestRng::default();
+        let deserialize = |json: &serde_json::Value| serde_json::from_value::<LabeledPolynomial<Fr>>(json.clone());
+
+        let p = LabeledPolynomial::new("test".to_string(), DensePolynomial::<Fr>::rand(4, rng), None, None);
+        assert!(deserialize(&serde_json::to_value(&p).unwrap()).is_ok());
+
+        // As in the canonical format, a dense polynomial with trailing zeros is rejected.
+        let mut coeffs = p.to_dense().coeffs.clone();
+        coeffs.push(Fr::zero());
+        let trailing_zero = LabeledPolynomial::new("test".to_string(), DensePolynomial { coeffs }, None, None);
+        assert!(deserialize(&serde_json::to_value(&trailing_zero).unwrap()).is_err());
+
+        // So is a sparse polynomial whose terms are not in increasing order of degree.
+        let sparse = SparsePolynomial::from_coefficients([(1, Fr::rand(rng)), (5, Fr::rand(rng))]);
+        let mut json = serde_json::to_value(LabeledPolynomial::new("test".to_string(), sparse, None, None)).unwrap();
+        assert!(deserialize(&json).is_ok());
+        json["polynomial"]["Sparse"]["coeffs"].as_array_mut().unwrap().reverse();
+        assert!(deserialize(&json).is_err());
+    }
+
+    #[test]
+    fn test_try_evaluate() {
+        let rng = &mut TestRng::default();
+
+        let mut dense = DensePolynomial::<Fr>::rand(10, rng);
+        let point = Fr::rand(rng);
+        let canonical = LabeledPolynomial::new("canonical".to_string(), dense.clone(), None, None);
+        assert_eq!(canonical.try_evaluate(point).unwrap(), dense.evaluate(point));
+
+        dense.coeffs.push(Fr::zero());
+        let non_canonical = LabeledPolynomial::new("non_canonical".to_string(), dense, None, None);
+        assert!(matches!(
+            non_canonical.try_evaluate(point),
+            Err(PCError::NonCanonicalPolynomial { label }) if label == "non_canonical"
+        ));
+    }
+
+    #[test]
+    fn test_representation_introspection() {
+        let rng = &mut TestRng::default();
+
+        let dense = LabeledPolynomial::new("dense".to_string(), DensePolynomial::<Fr>::rand(10, rng), None, None);
+        assert!(dense.is_dense() && !dense.is_sparse());
+        assert_eq!(dense.num_stored_coefficients(), 11);
+
+        let sparse = SparsePolynomial::from_coefficients([(3, Fr::rand(rng)), (700, Fr::rand(rng))]);
+        let mut sparse = LabeledPolynomial::new("sparse".to_string(), sparse, None, None);
+        assert!(sparse.is_sparse() && !sparse.is_dense());
+        assert_eq!(sparse.num_stored_coefficients(), 2);
+
+        // Setting a coefficient keeps the sparse representation.
+        sparse.set_coefficient(5, Fr::one());
+        assert!(sparse.is_sparse());
+        assert_eq!(sparse.num_stored_coefficients(), 3);
+
+        let zero = LabeledPolynomial::new("zero".to_string(), SparsePolynomial::<Fr>::zero(), None, None);
+        assert_eq!(zero.num_stored_coefficients(), 0);
+    }
+
+    #[test]
+    fn test_labeled_polynomial_into_parts() {
+        let rng = &mut TestRng::default();
+
+        let dense = DensePolynomial::<Fr>::rand(10, rng);
+        let sparse = SparsePolynomial::from_coefficients([(3, Fr::rand(rng)), (7, Fr::rand(rng))]);
+        let labeled = LabeledPolynomial::new("dense".to_string(), dense.clone(), Some(12), Some(1));
+        assert_eq!(labeled.clone().into_dense(), dense);
+        let (label, polynomial, degree_bound, hiding_bound) = labeled.into_parts();
+        assert_eq!((label.as_str(), degree_bound, hiding_bound), ("dense", Some(12), Some(1)));
+        assert_eq!(polynomial, Polynomial::from(dense));
+
+        let labeled = LabeledPolynomial::new("sparse".to_string(), sparse.clone(), None, None);
+        assert_eq!(labeled.into_dense(), DensePolynomial::from(sparse));
+    }
+
+    #[test]
+    fn test_with_metadata_from() {
+        let rng = &mut TestRng::default();
+
+        let template = LabeledPolynomial::new("a".to_string(), DensePolynomial::<Fr>::rand(4, rng), Some(8), Some(1));
+        let sparse = SparsePolynomial::from_coefficients([(2, Fr::rand(rng))]);
+        let labeled = LabeledPolynomial::with_metadata_from(&template, sparse.clone());
+        assert_eq!(labeled.info(), template.info());
+        assert_eq!(labeled.polynomial(), &Polynomial::from(sparse.clone()));
+    }
 }
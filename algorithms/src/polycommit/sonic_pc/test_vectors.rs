@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for checking a setup of [`SonicKZG10`] against the commit, open and verify flow.
+
+use super::{CommitterUnionKey, Evaluations, LabeledPolynomial, PolynomialLabel, QuerySet, SonicKZG10};
+use crate::{fft::DensePolynomial, polycommit::PCError, AlgebraicSponge};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::rand::Uniform;
+
+use rand::Rng;
+use rand_core::RngCore;
+
+/// Commit to a random hiding polynomial of degree at most `max_degree`, open it at a random point,
+/// and verify the opening, using an SRS loaded for `max_degree`.
+/// Returns an error if any step fails, including if the opening does not verify,
+/// and `PCError::DegreeIsZero` if `max_degree` is zero, as trimming to degree zero is not supported.
+pub fn round_trip<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>, R: RngCore>(
+    max_degree: usize,
+    rng: &mut R,
+) -> Result<(), PCError> {
+    if max_degree == 0 {
+        return Err(PCError::DegreeIsZero);
+    }
+
+    // Load the SRS, and trim it to the degree of the polynomial.
+    let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
+    let universal_prover = &pp.to_universal_prover()?;
+    let degree = rng.gen_range(1..=max_degree);
+    let (ck, vk) = SonicKZG10::<E, S>::trim(&pp, degree, None, 1, None)?;
+    let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+    // Commit to a random polynomial.
+    let label = PolynomialLabel::new("round_trip");
+    let polynomial = LabeledPolynomial::new(label.clone(), DensePolynomial::rand(degree, rng), None, Some(1));
+    let (commitments, randomness) =
+        SonicKZG10::<E, S>::commit(universal_prover, &ck, [(&polynomial).into()], Some(rng))?;
+
+    // Open it at a random point.
+    let point = E::Fr::rand(rng);
+    let mut query_set = QuerySet::new();
+    query_set.insert((label.clone(), ("rand".into(), point)));
+    let mut values = Evaluations::new();
+    values.insert((label, point), polynomial.evaluate(point));
+    let proof = SonicKZG10::batch_open(
+        universal_prover,
+        &ck,
+        [&polynomial],
+        &commitments,
+        &query_set,
+        &randomness,
+        &mut S::new(),
+    )?;
+
+    // Verify the opening.
+    SonicKZG10::batch_check_detailed(&vk, &commitments, &query_set, &values, &proof, &mut S::new(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+    use snarkvm_utilities::rand::TestRng;
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+
+    #[test]
+    fn test_round_trip() {
+        let rng = &mut TestRng::default();
+        for max_degree in [1, 10, 100] {
+            round_trip::<Bls12_377, Sponge, _>(max_degree, rng).unwrap();
+        }
+        assert!(matches!(round_trip::<Bls12_377, Sponge, _>(0, rng), Err(PCError::DegreeIsZero)));
+    }
+}
@@ -26,7 +26,7 @@ use super::sonic_pc::{
 use crate::{
     fft::DensePolynomial,
     polycommit::{
-        sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis, LinearCombination},
+        sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis, LinearCombination, PolynomialLabel},
         PCError,
     },
     srs::UniversalVerifier,
@@ -80,7 +80,7 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -
         let mut degree_bounds = Vec::new();
 
         for i in 0..10 {
-            let label = format!("Test{i}");
+            let label = PolynomialLabel::from(format!("Test{i}"));
             labels.push(label.clone());
             let poly = DensePolynomial::rand(supported_degree, rng);
 
@@ -156,7 +156,7 @@ pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
         // Generate polynomials
         let num_points_in_query_set = distributions::Uniform::from(1..=max_num_queries).sample(rng);
         for i in 0..num_polynomials {
-            let label = format!("Test{i}");
+            let label = PolynomialLabel::from(format!("Test{i}"));
             labels.push(label.clone());
             let eval_size: usize = distributions::Uniform::from(1..eval_size).sample(rng).next_power_of_two();
             let mut evals = vec![E::Fr::zero(); eval_size];
@@ -280,7 +280,7 @@ where
         // Generate polynomials
         let num_points_in_query_set = distributions::Uniform::from(1..=max_num_queries).sample(rng);
         for i in 0..num_polynomials {
-            let label = format!("Test{i}");
+            let label = PolynomialLabel::from(format!("Test{i}"));
             labels.push(label.clone());
             let degree = distributions::Uniform::from(1..=supported_degree).sample(rng);
             let poly = DensePolynomial::rand(degree, rng);
@@ -409,7 +409,7 @@ fn equation_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
         // Generate polynomials
         let num_points_in_query_set = distributions::Uniform::from(1..=max_num_queries).sample(rng);
         for i in 0..num_polynomials {
-            let label = format!("Test{i}");
+            let label = PolynomialLabel::from(format!("Test{i}"));
             labels.push(label.clone());
             let degree = distributions::Uniform::from(1..=supported_degree).sample(rng);
             let poly = DensePolynomial::rand(degree, rng);
@@ -466,7 +466,7 @@ fn equation_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
         for i in 0..num_points_in_query_set {
             let point = E::Fr::rand(rng);
             for j in 0..num_equations.unwrap() {
-                let label = format!("query {i} eqn {j}");
+                let label = PolynomialLabel::from(format!("query {i} eqn {j}"));
                 let mut lc = LinearCombination::empty(label.clone());
 
                 let mut value = E::Fr::zero();
@@ -17,7 +17,7 @@ use crate::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         EvaluationDomain,
     },
-    polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination},
+    polycommit::sonic_pc::{indexed, LCTerm, LabeledPolynomial, LinearCombination, PolynomialLabel},
     r1cs::SynthesisError,
     snark::marlin::{
         ahp::{matrices, verifier, AHPError, CircuitId, CircuitInfo},
@@ -46,8 +46,8 @@ struct VerifierChallenges<F: Field> {
     gamma: F,
 }
 
-pub(crate) fn witness_label(circuit_id: CircuitId, poly: &str, i: usize) -> String {
-    format!("circuit_{circuit_id}_{poly}_{i:0>8}")
+pub(crate) fn witness_label(circuit_id: CircuitId, poly: &str, i: usize) -> PolynomialLabel {
+    indexed(&format!("circuit_{circuit_id}_{poly}"), i)
 }
 
 pub(crate) struct ConstraintDomains<F: PrimeField> {
@@ -184,7 +184,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         evals: &E,
         prover_third_message: &prover::ThirdMessage<F>,
         state: &verifier::State<F, MM>,
-    ) -> Result<BTreeMap<String, LinearCombination<F>>, AHPError> {
+    ) -> Result<BTreeMap<PolynomialLabel, LinearCombination<F>>, AHPError> {
         assert!(!public_inputs.is_empty());
         let largest_constraint_domain = state.max_constraint_domain;
         let max_non_zero_domain = state.largest_non_zero_domain;
@@ -480,7 +480,7 @@ pub trait EvaluationsProvider<F: PrimeField>: core::fmt::Debug {
 impl<F: PrimeField> EvaluationsProvider<F> for crate::polycommit::sonic_pc::Evaluations<F> {
     fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F, AHPError> {
         let key = (lc.label.clone(), point);
-        self.get(&key).copied().ok_or_else(|| AHPError::MissingEval(lc.label.clone()))
+        self.get(&key).copied().ok_or_else(|| AHPError::MissingEval(lc.label.to_string()))
     }
 }
 
@@ -116,10 +116,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         ids.flat_map(move |id| {
             matrices.iter().flat_map(move |matrix| {
                 [
-                    format!("circuit_{id}_row_{matrix}"),
-                    format!("circuit_{id}_col_{matrix}"),
-                    format!("circuit_{id}_val_{matrix}"),
-                    format!("circuit_{id}_row_col_{matrix}"),
+                    format!("circuit_{id}_row_{matrix}").into(),
+                    format!("circuit_{id}_col_{matrix}").into(),
+                    format!("circuit_{id}_val_{matrix}").into(),
+                    format!("circuit_{id}_row_col_{matrix}").into(),
                 ]
             })
         })
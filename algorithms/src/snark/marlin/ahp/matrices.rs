@@ -319,4 +319,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn deserialize_previously_serialized_arithmetization() {
+        use snarkvm_utilities::CanonicalDeserialize;
+
+        // An arithmetization serialized before `Polynomial::check` started rejecting non-canonical
+        // polynomials, as found in proving keys, still loads with validation.
+        let bytes = hex::decode(include_str!("resources/matrix_arithmetization.hex").trim()).unwrap();
+        let arith = MatrixArithmetization::<F>::deserialize_compressed(&bytes[..]).unwrap();
+
+        let c = vec![vec![], vec![(F::one(), 3)], vec![], vec![(F::one(), 1)]];
+        let constraint_domain = EvaluationDomain::new(4).unwrap();
+        let input_domain = EvaluationDomain::new(2).unwrap();
+        let interpolation_domain = EvaluationDomain::new(2).unwrap();
+        let (elements, eq_poly_vals) = precomputation_for_matrix_evals(&constraint_domain);
+        let evals =
+            matrix_evals(&c, &interpolation_domain, &constraint_domain, &input_domain, &elements, &eq_poly_vals);
+        assert_eq!(arith, arithmetize_matrix(&CircuitId([0; 32]), "c", evals));
+    }
 }
@@ -171,7 +171,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     }
 
     fn calc_w(
-        label: String,
+        label: PolynomialLabel,
         private_variables: Vec<F>,
         x_poly: DensePolynomial<F>,
         constraint_domain: EvaluationDomain<F>,
@@ -207,7 +207,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     }
 
     fn calc_z_m(
-        label: impl ToString,
+        label: PolynomialLabel,
         evaluations: Vec<F>,
         constraint_domain: EvaluationDomain<F>,
         circuit: &Circuit<F, MM>,
@@ -215,7 +215,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     ) -> PoolResult<F> {
         let should_randomize = MM::ZK && r.is_some();
         let v_H = constraint_domain.vanishing_polynomial();
-        let label = label.to_string();
         let poly_time = start_timer!(|| format!("Computing {label}"));
 
         let evals = EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain);
@@ -236,7 +235,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             &evals.evaluations,
         );
 
-        let poly_for_opening = LabeledPolynomial::new(label.to_string(), poly, None, Self::zk_bound());
+        let poly_for_opening = LabeledPolynomial::new(label.clone(), poly, None, Self::zk_bound());
         if should_randomize {
             assert!(poly_for_opening.degree() < constraint_domain.size() + Self::zk_bound().unwrap());
         } else {
@@ -56,7 +56,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                     a
                 },
             );
-        let h_2 = LabeledPolynomial::new("h_2".into(), lhs_sum, None, None);
+        let h_2 = LabeledPolynomial::new("h_2", lhs_sum, None, None);
         let oracles = prover::FourthOracles { h_2 };
         assert!(oracles.matches_info(&Self::fourth_round_polynomial_info()));
         Ok(oracles)
@@ -64,6 +64,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Output the degree bounds of oracles in the third round.
     pub fn fourth_round_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        [("h_2".into(), PolynomialInfo::new("h_2".into(), None, None))].into()
+        [("h_2".into(), PolynomialInfo::new("h_2", None, None))].into()
     }
 }
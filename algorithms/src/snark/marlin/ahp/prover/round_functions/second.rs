@@ -57,8 +57,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the degree bounds of oracles in the first round.
     pub fn second_round_polynomial_info(constraint_domain_size: usize) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         [
-            PolynomialInfo::new("g_1".into(), Some(constraint_domain_size - 2), Self::zk_bound()),
-            PolynomialInfo::new("h_1".into(), None, None),
+            PolynomialInfo::new("g_1", Some(constraint_domain_size - 2), Self::zk_bound()),
+            PolynomialInfo::new("h_1", None, None),
         ]
         .into_iter()
         .map(|info| (info.label().into(), info))
@@ -101,8 +101,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         assert!(h_1.degree() <= 2 * max_constraint_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
 
         let oracles = prover::SecondOracles {
-            g_1: LabeledPolynomial::new("g_1".into(), g_1, Some(max_constraint_domain.size() - 2), zk_bound),
-            h_1: LabeledPolynomial::new("h_1".into(), h_1, None, None),
+            g_1: LabeledPolynomial::new("g_1", g_1, Some(max_constraint_domain.size() - 2), zk_bound),
+            h_1: LabeledPolynomial::new("h_1", h_1, None, None),
         };
         assert!(oracles.matches_info(&Self::second_round_polynomial_info(state.max_constraint_domain.size())));
 
@@ -176,7 +176,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     #[allow(clippy::too_many_arguments)]
     fn matrix_sumcheck_helper(
-        label: String,
+        label: PolynomialLabel,
         non_zero_domain: EvaluationDomain<F>,
         arithmetization: &MatrixArithmetization<F>,
         alpha: F,
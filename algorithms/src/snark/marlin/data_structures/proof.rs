@@ -175,7 +175,7 @@ impl<F: PrimeField> Evaluations<F> {
 
 impl<F: PrimeField> Evaluations<F> {
     pub(crate) fn from_map(
-        map: &std::collections::BTreeMap<String, F>,
+        map: &BTreeMap<sonic_pc::PolynomialLabel, F>,
         batch_sizes: BTreeMap<CircuitId, usize>,
     ) -> Self {
         let mut z_b_evals_collect: BTreeMap<CircuitId, Vec<F>> = BTreeMap::new();
@@ -809,7 +809,7 @@ where
                 let eval = proof
                     .evaluations
                     .get(circuit_index as usize, &label)
-                    .ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                    .ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
                 evaluations.insert((label, q), eval);
             }
         }